@@ -1,16 +1,270 @@
-use protobuf_codegen_pure::Customize;
+use protobuf::UnknownValueRef;
+use std::fmt::Write;
+
+// Field numbers of the message options declared in api_options.proto
+const OPTION_ID: u32 = 1036;
+const OPTION_SOURCE: u32 = 1037;
+const OPTION_IFDEF: u32 = 1038;
+const OPTION_NO_DELAY: u32 = 1040;
+
+struct MessageDefinition {
+	name: String,
+	id: u64,
+	source: &'static str,
+	ifdef: Option<String>,
+	no_delay: bool,
+}
 
 fn main() {
 	let out_dir = std::env::var("OUT_DIR").unwrap();
 
-	protobuf_codegen_pure::Codegen::new()
-		.customize(Customize {
-			gen_mod_rs: Some(true),
-			..Default::default()
-		})
-		.out_dir(out_dir)
-		.input("src/api.proto")
+	println!("cargo:rerun-if-changed=src/api.proto");
+	println!("cargo:rerun-if-changed=src/api_options.proto");
+
+	// The protobuf types are generated on every build, so that they always match api.proto and the MessageType and
+	// Event definitions below
+	protobuf_codegen::Codegen::new()
+		.pure()
+		.out_dir(&out_dir)
 		.include("src/")
+		.inputs(["src/api.proto", "src/api_options.proto"])
 		.run()
-		.expect("protoc");
+		.expect("generate protobuf types");
+
+	let definitions = message_definitions();
+	std::fs::write(
+		std::path::Path::new(&out_dir).join("message_types.rs"),
+		generate_message_types(&definitions),
+	)
+	.expect("write message types");
+	std::fs::write(
+		std::path::Path::new(&out_dir).join("events.rs"),
+		generate_events(&definitions),
+	)
+	.expect("write events");
+}
+
+/// Read all messages that have an `id` option from api.proto.
+fn message_definitions() -> Vec<MessageDefinition> {
+	let parsed = protobuf_parse::Parser::new()
+		.pure()
+		.include("src/")
+		.input("src/api.proto")
+		.parse_and_typecheck()
+		.expect("parse api.proto");
+
+	let mut definitions = vec![];
+	for file in parsed
+		.file_descriptors
+		.iter()
+		.filter(|f| f.name() == "api.proto")
+	{
+		for message in &file.message_type {
+			let options = message
+				.options
+				.get_or_default()
+				.special_fields
+				.unknown_fields();
+			let Some(UnknownValueRef::Varint(id)) = options.get(OPTION_ID) else {
+				continue;
+			};
+
+			let source = match options.get(OPTION_SOURCE) {
+				Some(UnknownValueRef::Varint(1)) => "Server",
+				Some(UnknownValueRef::Varint(2)) => "Client",
+				_ => "Both",
+			};
+
+			let ifdef = match options.get(OPTION_IFDEF) {
+				Some(UnknownValueRef::LengthDelimited(ifdef)) => {
+					Some(String::from_utf8(ifdef.to_vec()).expect("ifdef is not UTF-8"))
+				}
+				_ => None,
+			};
+
+			let no_delay = matches!(
+				options.get(OPTION_NO_DELAY),
+				Some(UnknownValueRef::Varint(v)) if v != 0
+			);
+
+			definitions.push(MessageDefinition {
+				name: message.name().to_string(),
+				id,
+				source,
+				ifdef,
+				no_delay,
+			});
+		}
+	}
+
+	definitions.sort_by_key(|d| d.id);
+	definitions
+}
+
+fn generate_message_types(definitions: &[MessageDefinition]) -> String {
+	let mut out = String::new();
+	writeln!(
+		out,
+		"// Generated by build.rs from the message options in api.proto. Do not edit."
+	)
+	.unwrap();
+	writeln!(out).unwrap();
+
+	writeln!(
+		out,
+		"/// Type of a message exchanged with the device (the `id` option in `api.proto`)."
+	)
+	.unwrap();
+	writeln!(
+		out,
+		"#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, FromPrimitive)]"
+	)
+	.unwrap();
+	writeln!(out, "pub enum MessageType {{").unwrap();
+	for d in definitions {
+		writeln!(out, "\t{} = {},", d.name, d.id).unwrap();
+	}
+	writeln!(out, "}}").unwrap();
+	writeln!(out).unwrap();
+
+	writeln!(
+		out,
+		"/// Decodes the payload of a message into the generated protobuf type."
+	)
+	.unwrap();
+	writeln!(
+		out,
+		"pub(crate) type Decoder = fn(&[u8]) -> Result<Box<dyn protobuf::MessageDyn>, protobuf::Error>;"
+	)
+	.unwrap();
+	writeln!(out).unwrap();
+
+	writeln!(
+		out,
+		"/// Properties of a message type, as declared in `api.proto`."
+	)
+	.unwrap();
+	writeln!(out, "pub(crate) struct MessageDefinition {{").unwrap();
+	writeln!(out, "\tpub(crate) message_type: MessageType,").unwrap();
+	writeln!(out, "\tpub(crate) source: MessageSource,").unwrap();
+	writeln!(out, "\tpub(crate) ifdef: Option<&'static str>,").unwrap();
+	writeln!(out, "\tpub(crate) no_delay: bool,").unwrap();
+	writeln!(out, "\tpub(crate) decode: Decoder,").unwrap();
+	writeln!(out, "}}").unwrap();
+	writeln!(out).unwrap();
+
+	writeln!(out, "/// All message types, ordered by ID.").unwrap();
+	writeln!(
+		out,
+		"pub(crate) const MESSAGE_DEFINITIONS: &[MessageDefinition] = &["
+	)
+	.unwrap();
+	for d in definitions {
+		writeln!(out, "\tMessageDefinition {{").unwrap();
+		writeln!(out, "\t\tmessage_type: MessageType::{},", d.name).unwrap();
+		writeln!(out, "\t\tsource: MessageSource::{},", d.source).unwrap();
+		match &d.ifdef {
+			Some(ifdef) => writeln!(out, "\t\tifdef: Some({ifdef:?}),").unwrap(),
+			None => writeln!(out, "\t\tifdef: None,").unwrap(),
+		}
+		writeln!(out, "\t\tno_delay: {},", d.no_delay).unwrap();
+		writeln!(
+			out,
+			"\t\tdecode: |b| Ok(Box::new(<crate::api::{} as protobuf::Message>::parse_from_bytes(b)?)),",
+			d.name
+		)
+		.unwrap();
+		writeln!(out, "\t}},").unwrap();
+	}
+	writeln!(out, "];").unwrap();
+	out
+}
+
+/// Generate the `Event` enum, with a variant for each message the device may send.
+fn generate_events(definitions: &[MessageDefinition]) -> String {
+	let incoming: Vec<&MessageDefinition> = definitions
+		.iter()
+		.filter(|d| d.source != "Client")
+		.collect();
+
+	let mut out = String::new();
+	writeln!(
+		out,
+		"// Generated by build.rs from the message options in api.proto. Do not edit."
+	)
+	.unwrap();
+	writeln!(out).unwrap();
+
+	writeln!(out, "/// A message received from the device.").unwrap();
+	writeln!(out, "#[derive(Debug, Clone, PartialEq)]").unwrap();
+	writeln!(out, "#[allow(clippy::large_enum_variant)]").unwrap();
+	writeln!(out, "pub enum Event {{").unwrap();
+	for d in &incoming {
+		writeln!(out, "\t{0}(api::{0}),", d.name).unwrap();
+	}
+	writeln!(
+		out,
+		"\t/// A message the device is not expected to send, or of a type unknown to this crate."
+	)
+	.unwrap();
+	writeln!(
+		out,
+		"\tUnhandled {{ message_type: u32, payload: Vec<u8> }},"
+	)
+	.unwrap();
+	writeln!(out, "}}").unwrap();
+	writeln!(out).unwrap();
+
+	writeln!(out, "impl Event {{").unwrap();
+	writeln!(
+		out,
+		"\tpub(crate) fn decode(frame: &Frame) -> Result<Event, EspHomeError> {{"
+	)
+	.unwrap();
+	writeln!(
+		out,
+		"\t\tmatch FromPrimitive::from_u32(frame.message_type()) {{"
+	)
+	.unwrap();
+	for d in &incoming {
+		writeln!(
+			out,
+			"\t\t\tSome(MessageType::{0}) => Ok(Event::{0}(frame.parse()?)),",
+			d.name
+		)
+		.unwrap();
+	}
+	writeln!(out, "\t\t\t_ => Ok(Event::Unhandled {{").unwrap();
+	writeln!(out, "\t\t\t\tmessage_type: frame.message_type(),").unwrap();
+	writeln!(out, "\t\t\t\tpayload: frame.payload().to_vec(),").unwrap();
+	writeln!(out, "\t\t\t}}),").unwrap();
+	writeln!(out, "\t\t}}").unwrap();
+	writeln!(out, "\t}}").unwrap();
+	writeln!(out).unwrap();
+
+	writeln!(out, "\t/// The type of the message, if it is known.").unwrap();
+	writeln!(out, "\t#[must_use]").unwrap();
+	writeln!(
+		out,
+		"\tpub fn message_type(&self) -> Option<MessageType> {{"
+	)
+	.unwrap();
+	writeln!(out, "\t\tmatch self {{").unwrap();
+	for d in &incoming {
+		writeln!(
+			out,
+			"\t\t\tEvent::{0}(_) => Some(MessageType::{0}),",
+			d.name
+		)
+		.unwrap();
+	}
+	writeln!(
+		out,
+		"\t\t\tEvent::Unhandled {{ message_type, .. }} => FromPrimitive::from_u32(*message_type),"
+	)
+	.unwrap();
+	writeln!(out, "\t\t}}").unwrap();
+	writeln!(out, "\t}}").unwrap();
+	writeln!(out, "}}").unwrap();
+	out
 }
@@ -0,0 +1,51 @@
+use esphome::{Connection, EntityKind, MjpegWriter};
+use std::{error::Error, fs::File, io::BufWriter};
+use structopt::StructOpt;
+
+#[derive(StructOpt, Debug)]
+#[structopt(name = "camera")]
+struct Opt {
+	#[structopt(short, long, default_value = "10.10.3.148:6053")]
+	address: String,
+
+	#[structopt(short, long, default_value = "")]
+	password: String,
+
+	#[structopt(short, long)]
+	key: Option<String>,
+
+	/// File to write to: a single JPEG image, or an MJPEG stream with --stream
+	#[structopt(short, long, default_value = "snapshot.jpg")]
+	output: String,
+
+	/// Number of images to stream
+	#[structopt(long)]
+	stream: Option<usize>,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+	let opt = Opt::from_args();
+	let connection = match opt.key {
+		Some(key) => Connection::open_encrypted(opt.address, &key)?,
+		None => Connection::open(opt.address)?,
+	};
+	let mut ad = connection.connect()?.authenticate(&opt.password)?;
+	let camera = ad
+		.list_entities()?
+		.into_iter()
+		.find(|e| matches!(e.kind(), EntityKind::Camera(_)))
+		.ok_or("The device has no camera")?;
+	println!("Using camera {}", camera.name());
+
+	match opt.stream {
+		Some(count) => {
+			let mut writer = MjpegWriter::new(BufWriter::new(File::create(&opt.output)?));
+			for image in ad.stream(&camera)?.take(count) {
+				writer.write_frame(&image?)?;
+			}
+		}
+		None => std::fs::write(&opt.output, ad.snapshot(&camera)?)?,
+	}
+	println!("Written to {}", opt.output);
+	Ok(())
+}
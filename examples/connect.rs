@@ -1,8 +1,8 @@
-use esphome::Connection;
+use esphome::{Connection, StateFilter};
 use std::{
+	collections::HashMap,
 	error::Error,
-	net::TcpStream,
-	time::{Duration, SystemTime, UNIX_EPOCH},
+	time::{SystemTime, UNIX_EPOCH},
 };
 use structopt::StructOpt;
 
@@ -14,14 +14,17 @@ struct Opt {
 
 	#[structopt(short, long)]
 	password: Option<String>,
+
+	#[structopt(short, long)]
+	key: Option<String>,
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
 	let opt = Opt::from_args();
-	let mut stream = TcpStream::connect(opt.address)?;
-	let mut write_stream = stream.try_clone()?;
-
-	let connection = Connection::new(&mut stream, &mut write_stream);
+	let connection = match opt.key {
+		Some(key) => Connection::open_encrypted(opt.address, &key)?,
+		None => Connection::open(opt.address)?,
+	};
 	let device = connection.connect()?;
 	println!("Connected to {}", device.server_info());
 
@@ -36,16 +39,26 @@ fn main() -> Result<(), Box<dyn Error>> {
 		println!("Device time: {} our time: {}", ad.get_time()?, my_time);
 		println!("Device info={:?}", ad.device_info()?);
 
-		ad.subscribe_states()?;
 		let entities = ad.list_entities()?;
+		let names: HashMap<u32, String> = entities
+			.iter()
+			.map(|e| (e.key(), e.name().to_string()))
+			.collect();
 
-		loop {
-			ad.device.ping()?;
-			std::thread::sleep(Duration::from_secs(1));
+		ad.device
+			.connection
+			.on_state_change(StateFilter::All, move |change| {
+				println!(
+					"- {}: {:?} -> {:?}",
+					names.get(&change.key).map_or("?", String::as_str),
+					change.previous,
+					change.state
+				);
+			});
+		ad.subscribe_states()?;
 
-			for e in &entities {
-				println!("- {:?}: {:?}", e, ad.device.connection.get_last_state(e));
-			}
+		for event in ad.events() {
+			event?;
 		}
 	}
 
@@ -0,0 +1,68 @@
+use esphome::AsyncConnection;
+use std::{
+	error::Error,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use structopt::StructOpt;
+use tokio::net::TcpStream;
+
+#[derive(StructOpt, Debug)]
+#[structopt(name = "basic")]
+struct Opt {
+	#[structopt(short, long, default_value = "10.10.3.148:6053")]
+	address: String,
+
+	#[structopt(short, long)]
+	password: Option<String>,
+
+	#[structopt(short, long)]
+	key: Option<String>,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+	let opt = Opt::from_args();
+	let stream = TcpStream::connect(opt.address).await?;
+
+	let connection = match opt.key {
+		Some(key) => AsyncConnection::new_encrypted(stream, &key)?,
+		None => AsyncConnection::new(stream),
+	};
+	let device = connection.connect().await?;
+	println!("Connected to {}", device.server_info());
+
+	if let Some(password) = opt.password {
+		let mut ad = device.authenticate(&password).await?;
+		println!("Authenticated!");
+
+		ad.device.ping().await?;
+		println!("Pong!");
+
+		let my_time = (SystemTime::now().duration_since(UNIX_EPOCH)?).as_secs() as u32;
+		println!(
+			"Device time: {} our time: {}",
+			ad.get_time().await?,
+			my_time
+		);
+		println!("Device info={:?}", ad.device_info().await?);
+
+		ad.subscribe_states().await?;
+		let entities = ad.list_entities().await?;
+
+		let mut interval = tokio::time::interval(Duration::from_secs(1));
+		loop {
+			tokio::select! {
+				_ = interval.tick() => {
+					for e in &entities {
+						println!("- {:?}: {:?}", e, ad.device.connection.get_last_state(e));
+					}
+				}
+				event = ad.listen() => {
+					println!("Received {:?}", event?.message_type());
+				}
+			}
+		}
+	}
+
+	Ok(())
+}
@@ -0,0 +1,44 @@
+use esphome::{api::LogLevel, Connection};
+use std::error::Error;
+use structopt::StructOpt;
+
+#[derive(StructOpt, Debug)]
+#[structopt(name = "logs")]
+struct Opt {
+	#[structopt(short, long, default_value = "10.10.3.148:6053")]
+	address: String,
+
+	#[structopt(short, long, default_value = "")]
+	password: String,
+
+	#[structopt(short, long)]
+	key: Option<String>,
+
+	/// Also log the configuration of the device
+	#[structopt(long)]
+	dump_config: bool,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+	let opt = Opt::from_args();
+	let connection = match opt.key {
+		Some(key) => Connection::open_encrypted(opt.address, &key)?,
+		None => Connection::open(opt.address)?,
+	};
+	let device = connection.connect()?;
+	println!("Connected to {}", device.server_info());
+
+	let mut ad = device.authenticate(&opt.password)?;
+	ad.subscribe_logs(LogLevel::LOG_LEVEL_VERY_VERBOSE, opt.dump_config)?;
+
+	loop {
+		let record = ad.next_log()?;
+		match (record.tag(), record.line()) {
+			(Some(tag), Some(line)) => {
+				println!("{:?} [{tag}:{line}] {}", record.level(), record.message())
+			}
+			(Some(tag), None) => println!("{:?} [{tag}] {}", record.level(), record.message()),
+			_ => println!("{:?} {}", record.level(), record.message()),
+		}
+	}
+}
@@ -0,0 +1,415 @@
+use crate::{
+	api::{self, HelloResponse},
+	connection::{handle_unsolicited, take_pending_event, Unsolicited},
+	frame::{self, Frame, Framing, NoiseHandshake},
+	homeassistant::{HomeAssistantStateProvider, ServiceCall, ServiceCallRouter, StateImports},
+	model::State,
+	subscription::{StateChange, StateFilter, StateTracker},
+	AsyncDevice, Entity, EspHomeError, Event, MessageType, DEFAULT_MAX_FRAME_LENGTH,
+	DEFAULT_MAX_PENDING_EVENTS,
+};
+use std::{
+	collections::VecDeque,
+	sync::mpsc::{Receiver, Sender},
+	time::Duration,
+};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// Asynchronous counterpart of [`Connection`](crate::Connection) for tokio streams.
+///
+/// Incoming bytes and outgoing frames are buffered inside the connection, so a future that reads or writes messages
+/// can be dropped (for instance in `tokio::select!`) without losing or corrupting frames.
+pub struct AsyncConnection<S> {
+	stream: S,
+	framing: Framing,
+	read_buffer: Vec<u8>,
+	max_frame_length: usize,
+	write_buffer: Vec<u8>,
+	states: StateTracker,
+	service_calls: ServiceCallRouter,
+	imports: StateImports,
+	pending_events: VecDeque<Frame>,
+	max_pending_events: usize,
+	connected: bool,
+}
+
+impl<S> AsyncConnection<S>
+where
+	S: AsyncRead + AsyncWrite + Unpin,
+{
+	pub fn new(stream: S) -> AsyncConnection<S> {
+		AsyncConnection {
+			stream,
+			framing: Framing::Plaintext,
+			read_buffer: Vec::new(),
+			max_frame_length: DEFAULT_MAX_FRAME_LENGTH,
+			write_buffer: Vec::new(),
+			states: StateTracker::default(),
+			service_calls: ServiceCallRouter::default(),
+			imports: StateImports::default(),
+			pending_events: VecDeque::new(),
+			max_pending_events: DEFAULT_MAX_PENDING_EVENTS,
+			connected: true,
+		}
+	}
+
+	/// Create a connection that uses Noise encryption with the given pre-shared key. The key is the base64-encoded
+	/// value configured as `api: encryption: key:` on the device.
+	pub fn new_encrypted(stream: S, key: &str) -> Result<AsyncConnection<S>, EspHomeError> {
+		let mut connection = AsyncConnection::new(stream);
+		connection.framing = Framing::noise(key)?;
+		Ok(connection)
+	}
+
+	/// Set the maximum size of an incoming message. Receiving a larger message fails with
+	/// [`EspHomeError::FrameTooLarge`], after which the connection can no longer be used.
+	#[must_use]
+	pub fn with_max_frame_length(mut self, max_frame_length: usize) -> AsyncConnection<S> {
+		self.max_frame_length = max_frame_length;
+		self
+	}
+
+	/// Set how many received messages are kept for [`AsyncConnection::next_event`] while waiting for replies to
+	/// requests (or until the application calls it). When more arrive, the oldest ones are dropped.
+	#[must_use]
+	pub fn with_max_pending_events(mut self, max_pending_events: usize) -> AsyncConnection<S> {
+		self.max_pending_events = max_pending_events;
+		self
+	}
+
+	pub(crate) async fn send_message<M>(
+		&mut self,
+		message_type: MessageType,
+		message: &M,
+	) -> Result<(), EspHomeError>
+	where
+		M: protobuf::Message,
+	{
+		self.send_bytes(message_type, &message.write_to_bytes()?)
+			.await
+	}
+
+	async fn send_bytes(
+		&mut self,
+		message_type: MessageType,
+		body: &[u8],
+	) -> Result<(), EspHomeError> {
+		self.buffer_bytes(message_type, body)?;
+		self.flush().await
+	}
+
+	/// Encode a message into the write buffer, to be sent by the next flush.
+	fn buffer_bytes(&mut self, message_type: MessageType, body: &[u8]) -> Result<(), EspHomeError> {
+		let bytes = self.framing.encode(message_type as u32, body)?;
+		self.write_buffer.extend(bytes);
+		Ok(())
+	}
+
+	/// Write out any buffered outgoing bytes.
+	async fn flush(&mut self) -> Result<(), EspHomeError> {
+		let result = self.write_pending().await;
+		self.connected &= result.is_ok();
+		Ok(result?)
+	}
+
+	async fn write_pending(&mut self) -> Result<(), std::io::Error> {
+		while !self.write_buffer.is_empty() {
+			let written = self.stream.write(&self.write_buffer).await?;
+			if written == 0 {
+				return Err(std::io::Error::from(std::io::ErrorKind::WriteZero));
+			}
+			self.write_buffer.drain(0..written);
+		}
+		self.stream.flush().await
+	}
+
+	/// Read more bytes from the stream into the read buffer.
+	async fn fill(&mut self) -> Result<(), EspHomeError> {
+		let result = match self.stream.read_buf(&mut self.read_buffer).await {
+			Ok(0) => Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof)),
+			result => result,
+		};
+		self.connected &= result.is_ok();
+		result?;
+		Ok(())
+	}
+
+	async fn read_noise_frame(&mut self) -> Result<Vec<u8>, EspHomeError> {
+		loop {
+			if let Some(frame) =
+				frame::take_noise_frame(&mut self.read_buffer, self.max_frame_length)?
+			{
+				return Ok(frame);
+			}
+			self.fill().await?;
+		}
+	}
+
+	/// Perform the Noise handshake. Does nothing for plaintext connections.
+	async fn handshake(&mut self) -> Result<(), EspHomeError> {
+		let Some(psk) = self.framing.pending_handshake() else {
+			return Ok(());
+		};
+
+		let (handshake, hello) = NoiseHandshake::start(&psk)?;
+		self.write_buffer.extend(hello);
+		self.flush().await?;
+		NoiseHandshake::server_hello(&self.read_noise_frame().await?)?;
+		let transport = handshake.finish(&self.read_noise_frame().await?)?;
+		self.framing.complete_handshake(transport);
+		Ok(())
+	}
+
+	#[must_use]
+	pub fn get_last_state(&self, entity: &Entity) -> Option<State> {
+		self.states.get(entity.key()).cloned()
+	}
+
+	/// Receive the state changes selected by `filter` (for instance an `&Entity` or an `EntityType`) on a channel.
+	/// Changes are sent while the connection processes incoming messages, so the connection must be polled (for
+	/// instance with [`AsyncConnection::next_event`]) for them to arrive.
+	pub fn state_changes<F>(&mut self, filter: F) -> Receiver<StateChange>
+	where
+		F: Into<StateFilter>,
+	{
+		self.states.subscribe(filter.into())
+	}
+
+	/// Call `callback` for each state change selected by `filter`, with the previous and new state.
+	pub fn on_state_change<F, C>(&mut self, filter: F, callback: C)
+	where
+		F: Into<StateFilter>,
+		C: FnMut(&StateChange) + Send + 'static,
+	{
+		self.states.on_change(filter.into(), callback);
+	}
+
+	/// Call `handler` for each Home Assistant service call (or event) with the given name that the device requests
+	/// (see [`Connection::on_service_call`](crate::Connection::on_service_call)).
+	pub fn on_service_call<F>(&mut self, service: &str, handler: F)
+	where
+		F: FnMut(&ServiceCall) + Send + 'static,
+	{
+		self.service_calls.add(service, handler);
+	}
+
+	/// Answer the Home Assistant states that the device imports from `provider` (see
+	/// [`Connection::set_state_provider`](crate::Connection::set_state_provider)).
+	pub fn set_state_provider<P>(&mut self, provider: P) -> Sender<String>
+	where
+		P: HomeAssistantStateProvider + 'static,
+	{
+		self.imports.set_provider(Box::new(provider))
+	}
+
+	/// Send the device the current values of `entity_id` from the provider, if it imports them (see
+	/// [`Connection::home_assistant_state_changed`](crate::Connection::home_assistant_state_changed)).
+	pub async fn home_assistant_state_changed(
+		&mut self,
+		entity_id: &str,
+	) -> Result<(), EspHomeError> {
+		for response in self.imports.changed(entity_id) {
+			self.send_message(MessageType::HomeAssistantStateResponse, &response)
+				.await?;
+		}
+		Ok(())
+	}
+
+	pub(crate) fn last_state(&self, key: u32) -> Option<&State> {
+		self.states.get(key)
+	}
+
+	/// Whether the connection to the device is still up. This becomes false when reading from or writing to the
+	/// stream fails, when an invalid or too large frame is received, or when the device closes the connection.
+	#[must_use]
+	pub fn is_connected(&self) -> bool {
+		self.connected
+	}
+
+	/// Whether the entity is available: the connection is up and the device has reported a valid state for it.
+	#[must_use]
+	pub fn is_available(&self, entity: &Entity) -> bool {
+		self.connected
+			&& matches!(self.states.get(entity.key()), Some(state) if *state != State::Unknown)
+	}
+
+	pub(crate) async fn receive_message<M>(
+		&mut self,
+		message_type: MessageType,
+	) -> Result<M, EspHomeError>
+	where
+		M: protobuf::Message,
+	{
+		loop {
+			let frame = self.receive_frame().await?;
+			if frame.message_type() == (message_type as u32) {
+				return frame.parse();
+			}
+
+			// Not the reply we are waiting for; keep it for next_event
+			self.queue_event(frame);
+		}
+	}
+
+	/// Read the next frame from the stream. This is cancel safe.
+	async fn read_frame(&mut self) -> Result<Frame, EspHomeError> {
+		for response in self.imports.take_changes() {
+			self.buffer_bytes(
+				MessageType::HomeAssistantStateResponse,
+				&protobuf::Message::write_to_bytes(&response)?,
+			)?;
+		}
+
+		// Finish sending replies that may have been interrupted by cancellation
+		self.flush().await?;
+
+		loop {
+			let frame = self
+				.framing
+				.take_frame(&mut self.read_buffer, self.max_frame_length);
+
+			// The rest of the stream cannot be read after an invalid or rejected frame
+			self.connected &= frame.is_ok();
+			if let Some(frame) = frame? {
+				return Ok(frame);
+			}
+			self.fill().await?;
+		}
+	}
+
+	/// Handle pings, time requests and state updates. Returns whether the frame was handled. Replies are only buffered
+	/// here and sent by the next [`AsyncConnection::read_frame`], so that a cancelled future cannot lose the frame
+	/// after it has been taken from the read buffer.
+	fn process_unsolicited(&mut self, frame: &Frame) -> Result<bool, EspHomeError> {
+		match handle_unsolicited(
+			&mut self.states,
+			&mut self.service_calls,
+			&mut self.imports,
+			frame,
+		)? {
+			Unsolicited::NotHandled => Ok(false),
+			Unsolicited::Handled => Ok(true),
+			Unsolicited::Reply(message_type, body) => {
+				self.buffer_bytes(message_type, &body)?;
+				Ok(true)
+			}
+			Unsolicited::Disconnect(body) => {
+				self.buffer_bytes(MessageType::DisconnectResponse, &body)?;
+				self.connected = false;
+				Ok(true)
+			}
+		}
+	}
+
+	/// Keep a frame that was received while waiting for something else, to be returned by
+	/// [`AsyncConnection::next_event`].
+	pub(crate) fn queue_event(&mut self, frame: Frame) {
+		if self.pending_events.len() >= self.max_pending_events {
+			self.pending_events.pop_front();
+		}
+		if self.max_pending_events > 0 {
+			self.pending_events.push_back(frame);
+		}
+	}
+
+	/// Drop the kept frames for which `discard` returns true, for example because they are out of date.
+	pub(crate) fn discard_pending_events<F>(&mut self, mut discard: F)
+	where
+		F: FnMut(&Event) -> bool,
+	{
+		self.pending_events
+			.retain(|frame| !Event::decode(frame).is_ok_and(|event| discard(&event)));
+	}
+
+	/// Receive the next frame that is not handled internally (pings, time requests and state updates are).
+	pub(crate) async fn receive_frame(&mut self) -> Result<Frame, EspHomeError> {
+		loop {
+			let frame = self.read_frame().await?;
+			if !self.process_unsolicited(&frame)? {
+				return Ok(frame);
+			}
+
+			// Keep them for the application, which receives them from next_event
+			self.queue_event(frame);
+		}
+	}
+
+	/// Receive the next message from the device. Pings and time requests are answered and states are recorded (see
+	/// [`AsyncConnection::get_last_state`]) before the message is returned. This is cancel safe: replies are sent
+	/// when the next message is read.
+	///
+	/// Messages that arrive while waiting for the reply to a request are kept and returned by subsequent calls (up to
+	/// a limit, see [`AsyncConnection::with_max_pending_events`]).
+	pub async fn next_event(&mut self) -> Result<Event, EspHomeError> {
+		if let Some(frame) = self.pending_events.pop_front() {
+			return Event::decode(&frame);
+		}
+
+		let frame = self.read_frame().await?;
+		self.process_unsolicited(&frame)?;
+		Event::decode(&frame)
+	}
+
+	/// Receive the next message for which `select` returns a value, and return that value. Other messages are kept
+	/// for [`AsyncConnection::next_event`]. This is cancel safe.
+	pub(crate) async fn next_event_matching<T, F>(
+		&mut self,
+		mut select: F,
+	) -> Result<T, EspHomeError>
+	where
+		F: FnMut(&Event) -> Option<T>,
+	{
+		if let Some(selected) = take_pending_event(&mut self.pending_events, &mut select) {
+			return Ok(selected);
+		}
+		loop {
+			let frame = self.read_frame().await?;
+			self.process_unsolicited(&frame)?;
+			if let Some(selected) = select(&Event::decode(&frame)?) {
+				return Ok(selected);
+			}
+			self.queue_event(frame);
+		}
+	}
+
+	/// Like [`AsyncConnection::next_event_matching`], but returns `None` when no selected message arrived within
+	/// `timeout`.
+	pub(crate) async fn next_event_matching_timeout<T, F>(
+		&mut self,
+		timeout: Duration,
+		select: F,
+	) -> Result<Option<T>, EspHomeError>
+	where
+		F: FnMut(&Event) -> Option<T>,
+	{
+		match tokio::time::timeout(timeout, self.next_event_matching(select)).await {
+			Ok(selected) => selected.map(Some),
+			Err(_) => Ok(None),
+		}
+	}
+
+	pub(crate) async fn request<M, R>(
+		&mut self,
+		message_type: MessageType,
+		message: &M,
+		reply_type: MessageType,
+	) -> Result<R, EspHomeError>
+	where
+		M: protobuf::Message,
+		R: protobuf::Message,
+	{
+		self.send_message(message_type, message).await?;
+		self.receive_message::<R>(reply_type).await
+	}
+
+	pub async fn connect(mut self) -> Result<AsyncDevice<S>, EspHomeError> {
+		self.handshake().await?;
+
+		let mut hr = api::HelloRequest::new();
+		hr.client_info = "esphome.rs".to_string();
+		self.send_message(MessageType::HelloRequest, &hr).await?;
+
+		let hr: HelloResponse = self.receive_message(MessageType::HelloResponse).await?;
+		Ok(AsyncDevice::new(self, hr))
+	}
+}
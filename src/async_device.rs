@@ -0,0 +1,567 @@
+use crate::{
+	api::{self, ConnectResponse, HelloResponse},
+	camera::{self, ImageAssembler},
+	command::{
+		self, ClimateCommand, CoverCommand, FanCommand, LightCommand, MediaPlayerCommand,
+		ServiceValue,
+	},
+	device::{decode_listing, Listing},
+	AsyncConnection, BluetoothAdvertisement, DeviceInfo, Entity, EntityType, EspHomeError, Event,
+	LogRecord, MessageType, ServiceCall,
+};
+use std::{
+	collections::HashMap,
+	time::{Duration, Instant},
+};
+use tokio::io::{AsyncRead, AsyncWrite};
+
+/// Asynchronous counterpart of [`Device`](crate::Device).
+pub struct AsyncDevice<S> {
+	pub connection: AsyncConnection<S>,
+	hello_information: api::HelloResponse,
+}
+
+impl<S> AsyncDevice<S>
+where
+	S: AsyncRead + AsyncWrite + Unpin,
+{
+	pub(crate) fn new(
+		connection: AsyncConnection<S>,
+		hello_information: HelloResponse,
+	) -> AsyncDevice<S> {
+		AsyncDevice {
+			connection,
+			hello_information,
+		}
+	}
+
+	pub fn server_info(&self) -> String {
+		self.hello_information.server_info.clone()
+	}
+
+	/// Major and minor version of the API implemented by the device.
+	#[must_use]
+	pub fn api_version(&self) -> (u32, u32) {
+		(
+			self.hello_information.api_version_major,
+			self.hello_information.api_version_minor,
+		)
+	}
+
+	pub async fn authenticate(
+		mut self,
+		password: &str,
+	) -> Result<AsyncAuthenticatedDevice<S>, EspHomeError> {
+		let mut cr = api::ConnectRequest::new();
+		cr.password = password.to_string();
+		self.connection
+			.send_message(MessageType::ConnectRequest, &cr)
+			.await?;
+		let cr: ConnectResponse = self
+			.connection
+			.receive_message(MessageType::ConnectResponse)
+			.await?;
+
+		if cr.invalid_password {
+			return Err(EspHomeError::InvalidPassword);
+		}
+
+		Ok(AsyncAuthenticatedDevice::new(self))
+	}
+
+	pub async fn ping(&mut self) -> Result<(), EspHomeError> {
+		let _r: api::PingResponse = self
+			.connection
+			.request(
+				MessageType::PingRequest,
+				&api::PingRequest::new(),
+				MessageType::PingResponse,
+			)
+			.await?;
+		Ok(())
+	}
+
+	pub async fn disconnect(mut self) -> Result<(), EspHomeError> {
+		let _r: api::DisconnectResponse = self
+			.connection
+			.request(
+				MessageType::DisconnectRequest,
+				&api::DisconnectRequest::new(),
+				MessageType::DisconnectResponse,
+			)
+			.await?;
+		Ok(())
+	}
+}
+
+/// Asynchronous counterpart of [`AuthenticatedDevice`](crate::AuthenticatedDevice).
+pub struct AsyncAuthenticatedDevice<S> {
+	pub device: AsyncDevice<S>,
+
+	/// Types of the entities listed by the device, by key.
+	entities: HashMap<u32, EntityType>,
+}
+
+impl<S> AsyncAuthenticatedDevice<S>
+where
+	S: AsyncRead + AsyncWrite + Unpin,
+{
+	fn new(device: AsyncDevice<S>) -> AsyncAuthenticatedDevice<S> {
+		AsyncAuthenticatedDevice {
+			device,
+			entities: HashMap::new(),
+		}
+	}
+
+	pub async fn get_time(&mut self) -> Result<u32, EspHomeError> {
+		let r: api::GetTimeResponse = self
+			.device
+			.connection
+			.request(
+				MessageType::GetTimeRequest,
+				&api::GetTimeRequest::new(),
+				MessageType::GetTimeResponse,
+			)
+			.await?;
+		Ok(r.epoch_seconds)
+	}
+
+	pub async fn device_info(&mut self) -> Result<DeviceInfo, EspHomeError> {
+		let r: api::DeviceInfoResponse = self
+			.device
+			.connection
+			.request(
+				MessageType::DeviceInfoRequest,
+				&api::DeviceInfoRequest::new(),
+				MessageType::DeviceInfoResponse,
+			)
+			.await?;
+		Ok(DeviceInfo::new(r))
+	}
+
+	/// Wait for the next message from the device (see [`AsyncConnection::next_event`]). This is cancel safe. Messages
+	/// that other methods wait for are taken by them, as with
+	/// [`AuthenticatedDevice::listen`](crate::AuthenticatedDevice::listen).
+	pub async fn listen(&mut self) -> Result<Event, EspHomeError> {
+		self.device.connection.next_event().await
+	}
+
+	pub async fn subscribe_states(&mut self) -> Result<(), EspHomeError> {
+		self.device
+			.connection
+			.send_message(
+				MessageType::SubscribeStatesRequest,
+				&api::SubscribeStatesRequest::new(),
+			)
+			.await
+	}
+
+	/// Ask the device to send log messages (see
+	/// [`AuthenticatedDevice::subscribe_logs`](crate::AuthenticatedDevice::subscribe_logs)).
+	pub async fn subscribe_logs(
+		&mut self,
+		level: api::LogLevel,
+		dump_config: bool,
+	) -> Result<(), EspHomeError> {
+		let mut request = api::SubscribeLogsRequest::new();
+		request.level = level.into();
+		request.dump_config = dump_config;
+		self.device
+			.connection
+			.send_message(MessageType::SubscribeLogsRequest, &request)
+			.await
+	}
+
+	/// Wait for the next log message (see [`AuthenticatedDevice::next_log`](crate::AuthenticatedDevice::next_log)).
+	/// This is cancel safe.
+	pub async fn next_log(&mut self) -> Result<LogRecord, EspHomeError> {
+		self.device
+			.connection
+			.next_event_matching(|event| match event {
+				Event::SubscribeLogsResponse(response) => Some(LogRecord::from(response)),
+				_ => None,
+			})
+			.await
+	}
+
+	/// Ask the device to send the Home Assistant service calls and events it makes (see
+	/// [`AuthenticatedDevice::subscribe_home_assistant_services`](crate::AuthenticatedDevice::subscribe_home_assistant_services)).
+	pub async fn subscribe_home_assistant_services(&mut self) -> Result<(), EspHomeError> {
+		self.device
+			.connection
+			.send_message(
+				MessageType::SubscribeHomeassistantServicesRequest,
+				&api::SubscribeHomeassistantServicesRequest::new(),
+			)
+			.await
+	}
+
+	/// Ask the device which Home Assistant states it imports (see
+	/// [`AuthenticatedDevice::subscribe_home_assistant_states`](crate::AuthenticatedDevice::subscribe_home_assistant_states)).
+	pub async fn subscribe_home_assistant_states(&mut self) -> Result<(), EspHomeError> {
+		self.device
+			.connection
+			.send_message(
+				MessageType::SubscribeHomeAssistantStatesRequest,
+				&api::SubscribeHomeAssistantStatesRequest::new(),
+			)
+			.await
+	}
+
+	/// Wait for the next Home Assistant service call or event (see
+	/// [`AuthenticatedDevice::next_service_call`](crate::AuthenticatedDevice::next_service_call)).
+	pub async fn next_service_call(&mut self) -> Result<ServiceCall, EspHomeError> {
+		self.device
+			.connection
+			.next_event_matching(|event| match event {
+				Event::HomeassistantServiceResponse(response) => Some(ServiceCall::from(response)),
+				_ => None,
+			})
+			.await
+	}
+
+	/// Ask the device to send the Bluetooth LE advertisements it receives (see
+	/// [`AuthenticatedDevice::subscribe_bluetooth_advertisements`](crate::AuthenticatedDevice::subscribe_bluetooth_advertisements)).
+	pub async fn subscribe_bluetooth_advertisements(&mut self) -> Result<(), EspHomeError> {
+		if self.device_info().await?.bluetooth_proxy_version() == 0 {
+			return Err(EspHomeError::NoBluetoothProxy);
+		}
+		self.device
+			.connection
+			.send_message(
+				MessageType::SubscribeBluetoothLEAdvertisementsRequest,
+				&api::SubscribeBluetoothLEAdvertisementsRequest::new(),
+			)
+			.await
+	}
+
+	/// Wait for the next Bluetooth LE advertisement (see
+	/// [`AuthenticatedDevice::next_bluetooth_advertisement`](crate::AuthenticatedDevice::next_bluetooth_advertisement)).
+	pub async fn next_bluetooth_advertisement(
+		&mut self,
+	) -> Result<BluetoothAdvertisement, EspHomeError> {
+		self.device
+			.connection
+			.next_event_matching(|event| match event {
+				Event::BluetoothLEAdvertisementResponse(response) => {
+					Some(BluetoothAdvertisement::from(response))
+				}
+				_ => None,
+			})
+			.await
+	}
+
+	pub async fn list_entities(&mut self) -> Result<Vec<Entity>, EspHomeError> {
+		self.device
+			.connection
+			.send_message(
+				MessageType::ListEntitiesRequest,
+				&api::ListEntitiesRequest::new(),
+			)
+			.await?;
+
+		let mut entities: Vec<Entity> = vec![];
+
+		loop {
+			let frame = self.device.connection.receive_frame().await?;
+
+			match decode_listing(&frame)? {
+				Some(Listing::Entity(entity)) => {
+					self.entities.insert(entity.key(), entity.entity_type());
+					entities.push(entity);
+				}
+				Some(Listing::Done) => break,
+
+				// Anything else that arrives while listing is kept for next_event
+				None => self.device.connection.queue_event(frame),
+			}
+		}
+
+		Ok(entities)
+	}
+
+	pub async fn switch_on(&mut self, entity: &Entity) -> Result<(), EspHomeError> {
+		self.set_switch(entity, true).await
+	}
+
+	pub async fn switch_off(&mut self, entity: &Entity) -> Result<(), EspHomeError> {
+		self.set_switch(entity, false).await
+	}
+
+	/// Turn the switch on if its last known state is off, and vice versa (see
+	/// [`AuthenticatedDevice::toggle_switch`](crate::AuthenticatedDevice::toggle_switch)).
+	pub async fn toggle_switch(&mut self, entity: &Entity) -> Result<(), EspHomeError> {
+		command::check_entity(&self.entities, entity, EntityType::Switch)?;
+		let state = command::toggled(entity, self.device.connection.last_state(entity.key()))?;
+		self.set_switch(entity, state).await
+	}
+
+	/// Send a command to a light (see
+	/// [`AuthenticatedDevice::light_command`](crate::AuthenticatedDevice::light_command)).
+	pub async fn light_command(
+		&mut self,
+		entity: &Entity,
+		command: &LightCommand,
+	) -> Result<(), EspHomeError> {
+		let request = command::light_command(&self.entities, entity, command)?;
+		self.device
+			.connection
+			.send_message(MessageType::LightCommandRequest, &request)
+			.await
+	}
+
+	/// Send a command to a cover (see
+	/// [`AuthenticatedDevice::cover_command`](crate::AuthenticatedDevice::cover_command)).
+	pub async fn cover_command(
+		&mut self,
+		entity: &Entity,
+		command: &CoverCommand,
+	) -> Result<(), EspHomeError> {
+		let request =
+			command::cover_command(&self.entities, entity, command, self.device.api_version())?;
+		self.device
+			.connection
+			.discard_pending_events(|event| command::is_cover_state(entity, event));
+		self.device
+			.connection
+			.send_message(MessageType::CoverCommandRequest, &request)
+			.await
+	}
+
+	/// Send a command to a fan (see [`AuthenticatedDevice::fan_command`](crate::AuthenticatedDevice::fan_command)).
+	pub async fn fan_command(
+		&mut self,
+		entity: &Entity,
+		command: &FanCommand,
+	) -> Result<(), EspHomeError> {
+		let request =
+			command::fan_command(&self.entities, entity, command, self.device.api_version())?;
+		self.device
+			.connection
+			.send_message(MessageType::FanCommandRequest, &request)
+			.await
+	}
+
+	/// Send a command to a climate device (see
+	/// [`AuthenticatedDevice::climate_command`](crate::AuthenticatedDevice::climate_command)).
+	pub async fn climate_command(
+		&mut self,
+		entity: &Entity,
+		command: &ClimateCommand,
+	) -> Result<(), EspHomeError> {
+		let request =
+			command::climate_command(&self.entities, entity, command, self.device.api_version())?;
+		self.device
+			.connection
+			.send_message(MessageType::ClimateCommandRequest, &request)
+			.await
+	}
+
+	/// Set a number, rejecting values outside of its range (see
+	/// [`AuthenticatedDevice::set_number`](crate::AuthenticatedDevice::set_number)).
+	pub async fn set_number(&mut self, entity: &Entity, value: f32) -> Result<f32, EspHomeError> {
+		let request = command::number_command(&self.entities, entity, value, false)?;
+		self.device
+			.connection
+			.send_message(MessageType::NumberCommandRequest, &request)
+			.await?;
+		Ok(request.state)
+	}
+
+	/// Set a number, clamping the value to its range (see
+	/// [`AuthenticatedDevice::set_number_clamped`](crate::AuthenticatedDevice::set_number_clamped)).
+	pub async fn set_number_clamped(
+		&mut self,
+		entity: &Entity,
+		value: f32,
+	) -> Result<f32, EspHomeError> {
+		let request = command::number_command(&self.entities, entity, value, true)?;
+		self.device
+			.connection
+			.send_message(MessageType::NumberCommandRequest, &request)
+			.await?;
+		Ok(request.state)
+	}
+
+	/// Select one of the options of a select (see
+	/// [`AuthenticatedDevice::select_option`](crate::AuthenticatedDevice::select_option)).
+	pub async fn select_option(
+		&mut self,
+		entity: &Entity,
+		option: &str,
+	) -> Result<(), EspHomeError> {
+		let request = command::select_command(&self.entities, entity, option)?;
+		self.device
+			.connection
+			.send_message(MessageType::SelectCommandRequest, &request)
+			.await
+	}
+
+	pub async fn press_button(&mut self, entity: &Entity) -> Result<(), EspHomeError> {
+		let request = command::button_command(&self.entities, entity)?;
+		self.device
+			.connection
+			.send_message(MessageType::ButtonCommandRequest, &request)
+			.await
+	}
+
+	/// Lock, unlock or open a lock (see
+	/// [`AuthenticatedDevice::lock_command`](crate::AuthenticatedDevice::lock_command)).
+	pub async fn lock_command(
+		&mut self,
+		entity: &Entity,
+		command: api::LockCommand,
+		code: Option<&str>,
+	) -> Result<(), EspHomeError> {
+		let request = command::lock_command(&self.entities, entity, command, code)?;
+		self.device
+			.connection
+			.send_message(MessageType::LockCommandRequest, &request)
+			.await
+	}
+
+	/// Send a command to a media player (see
+	/// [`AuthenticatedDevice::media_player_command`](crate::AuthenticatedDevice::media_player_command)).
+	pub async fn media_player_command(
+		&mut self,
+		entity: &Entity,
+		command: &MediaPlayerCommand,
+	) -> Result<(), EspHomeError> {
+		let request = command::media_player_command(&self.entities, entity, command)?;
+		self.device
+			.connection
+			.send_message(MessageType::MediaPlayerCommandRequest, &request)
+			.await
+	}
+
+	/// Execute a user-defined service (see
+	/// [`AuthenticatedDevice::execute_service`](crate::AuthenticatedDevice::execute_service)).
+	pub async fn execute_service(
+		&mut self,
+		entity: &Entity,
+		arguments: &[ServiceValue],
+	) -> Result<(), EspHomeError> {
+		let request = command::execute_service_command(
+			&self.entities,
+			entity,
+			arguments,
+			self.device.api_version(),
+		)?;
+		self.device
+			.connection
+			.send_message(MessageType::ExecuteServiceRequest, &request)
+			.await
+	}
+
+	/// Wait for a state update reporting that the cover has stopped moving (see
+	/// [`AuthenticatedDevice::wait_for_cover_idle`](crate::AuthenticatedDevice::wait_for_cover_idle)).
+	pub async fn wait_for_cover_idle(
+		&mut self,
+		entity: &Entity,
+		timeout: Duration,
+	) -> Result<(), EspHomeError> {
+		command::check_entity(&self.entities, entity, EntityType::Cover)?;
+		self.device
+			.connection
+			.next_event_matching_timeout(timeout, |event| {
+				command::is_cover_idle(entity, event).then_some(())
+			})
+			.await?
+			.ok_or(EspHomeError::Timeout)
+	}
+
+	/// Request a single image from the camera and wait until all of it has arrived (see
+	/// [`AuthenticatedDevice::snapshot`](crate::AuthenticatedDevice::snapshot)).
+	pub async fn snapshot(&mut self, camera: &Entity) -> Result<Vec<u8>, EspHomeError> {
+		command::check_entity(&self.entities, camera, EntityType::Camera)?;
+		self.device
+			.connection
+			.send_message(
+				MessageType::CameraImageRequest,
+				&camera::image_request(false),
+			)
+			.await?;
+		let mut image = ImageAssembler::new(camera.key());
+		loop {
+			let chunk = self
+				.device
+				.connection
+				.next_event_matching(|event| image.accepts(event).then(|| image.push(event)))
+				.await?;
+			if let Some(jpeg) = chunk {
+				return Ok(jpeg);
+			}
+		}
+	}
+
+	/// Stream images from the camera (see [`AuthenticatedDevice::stream`](crate::AuthenticatedDevice::stream)).
+	/// Images are returned by [`AsyncCameraStream::next_image`].
+	pub async fn stream(
+		&mut self,
+		camera: &Entity,
+	) -> Result<AsyncCameraStream<'_, S>, EspHomeError> {
+		command::check_entity(&self.entities, camera, EntityType::Camera)?;
+		self.device
+			.connection
+			.send_message(
+				MessageType::CameraImageRequest,
+				&camera::image_request(true),
+			)
+			.await?;
+		Ok(AsyncCameraStream {
+			connection: &mut self.device.connection,
+			image: ImageAssembler::new(camera.key()),
+			requested: Instant::now(),
+		})
+	}
+
+	async fn set_switch(&mut self, entity: &Entity, state: bool) -> Result<(), EspHomeError> {
+		let command = command::switch_command(&self.entities, entity, state)?;
+		self.device
+			.connection
+			.send_message(MessageType::SwitchCommandRequest, &command)
+			.await
+	}
+}
+
+/// Images of a camera, returned by [`AsyncAuthenticatedDevice::stream`].
+pub struct AsyncCameraStream<'a, S> {
+	connection: &'a mut AsyncConnection<S>,
+	image: ImageAssembler,
+	requested: Instant,
+}
+
+impl<S> AsyncCameraStream<'_, S>
+where
+	S: AsyncRead + AsyncWrite + Unpin,
+{
+	/// Wait for the next image from the camera, and return its JPEG data. Images keep being requested for as long as
+	/// this is called.
+	pub async fn next_image(&mut self) -> Result<Vec<u8>, EspHomeError> {
+		loop {
+			let Some(wait) = camera::STREAM_REQUEST_INTERVAL
+				.checked_sub(self.requested.elapsed())
+				.filter(|wait| !wait.is_zero())
+			else {
+				self.connection
+					.send_message(
+						MessageType::CameraImageRequest,
+						&camera::image_request(true),
+					)
+					.await?;
+				self.requested = Instant::now();
+				continue;
+			};
+			let image = &mut self.image;
+			let chunk = self
+				.connection
+				.next_event_matching_timeout(wait, |event| {
+					image.accepts(event).then(|| image.push(event))
+				})
+				.await?;
+			if let Some(Some(jpeg)) = chunk {
+				return Ok(jpeg);
+			}
+		}
+	}
+}
@@ -0,0 +1,127 @@
+use crate::api;
+use std::collections::HashMap;
+
+/// A Bluetooth LE advertisement received by a device acting as a Bluetooth proxy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BluetoothAdvertisement {
+	address: u64,
+	name: String,
+	rssi: i32,
+	service_uuids: Vec<String>,
+	service_data: HashMap<String, Vec<u8>>,
+	manufacturer_data: HashMap<String, Vec<u8>>,
+}
+
+/// Data by UUID (or manufacturer ID). Devices before API version 1.7 send each byte as a separate number.
+#[allow(clippy::cast_possible_truncation)]
+fn to_map(entries: &[api::BluetoothServiceData]) -> HashMap<String, Vec<u8>> {
+	entries
+		.iter()
+		.map(|e| {
+			let data = if e.data.is_empty() {
+				e.legacy_data.iter().map(|&b| b as u8).collect()
+			} else {
+				e.data.clone()
+			};
+			(e.uuid.clone(), data)
+		})
+		.collect()
+}
+
+impl From<&api::BluetoothLEAdvertisementResponse> for BluetoothAdvertisement {
+	fn from(response: &api::BluetoothLEAdvertisementResponse) -> Self {
+		BluetoothAdvertisement {
+			address: response.address,
+			name: response.name.clone(),
+			rssi: response.rssi,
+			service_uuids: response.service_uuids.clone(),
+			service_data: to_map(&response.service_data),
+			manufacturer_data: to_map(&response.manufacturer_data),
+		}
+	}
+}
+
+impl BluetoothAdvertisement {
+	/// The MAC address of the advertiser as a number (in its lower 48 bits).
+	#[must_use]
+	pub fn address(&self) -> u64 {
+		self.address
+	}
+
+	/// The MAC address of the advertiser, for example "AC:BC:32:89:0E:A9".
+	#[must_use]
+	pub fn mac_address(&self) -> String {
+		self.address.to_be_bytes()[2..]
+			.iter()
+			.map(|b| format!("{b:02X}"))
+			.collect::<Vec<_>>()
+			.join(":")
+	}
+
+	#[must_use]
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Received signal strength in dBm.
+	#[must_use]
+	pub fn rssi(&self) -> i32 {
+		self.rssi
+	}
+
+	#[must_use]
+	pub fn service_uuids(&self) -> &[String] {
+		&self.service_uuids
+	}
+
+	/// Service data by service UUID.
+	#[must_use]
+	pub fn service_data(&self) -> &HashMap<String, Vec<u8>> {
+		&self.service_data
+	}
+
+	/// Manufacturer data by manufacturer ID.
+	#[must_use]
+	pub fn manufacturer_data(&self) -> &HashMap<String, Vec<u8>> {
+		&self.manufacturer_data
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn service_data(uuid: &str, data: &[u8], legacy_data: &[u32]) -> api::BluetoothServiceData {
+		let mut service_data = api::BluetoothServiceData::new();
+		service_data.uuid = uuid.to_string();
+		service_data.data = data.to_vec();
+		service_data.legacy_data = legacy_data.to_vec();
+		service_data
+	}
+
+	#[test]
+	fn mac_address() {
+		let mut response = api::BluetoothLEAdvertisementResponse::new();
+		response.address = 0xA4C1_3801_02FF;
+		let advertisement = BluetoothAdvertisement::from(&response);
+		assert_eq!(advertisement.mac_address(), "A4:C1:38:01:02:FF");
+
+		response.address = 0x0E;
+		let advertisement = BluetoothAdvertisement::from(&response);
+		assert_eq!(advertisement.mac_address(), "00:00:00:00:00:0E");
+	}
+
+	#[test]
+	fn legacy_data() {
+		let mut response = api::BluetoothLEAdvertisementResponse::new();
+		response.service_data = vec![
+			service_data("180f", &[], &[0x12, 0xFF]),
+			service_data("181a", &[1, 2], &[3]),
+		];
+		response.manufacturer_data = vec![service_data("004c", &[], &[0x02, 0x15])];
+		let advertisement = BluetoothAdvertisement::from(&response);
+		assert_eq!(advertisement.service_data()["180f"], [0x12, 0xFF]);
+		assert_eq!(advertisement.service_data()["181a"], [1, 2]);
+		assert_eq!(advertisement.manufacturer_data()["004c"], [0x02, 0x15]);
+	}
+}
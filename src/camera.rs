@@ -0,0 +1,83 @@
+use crate::{api, Event};
+use std::{
+	io::{self, Write},
+	time::Duration,
+};
+
+/// The device stops streaming five seconds after the last stream request, so it is repeated well before that.
+pub(crate) const STREAM_REQUEST_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Boundary between the images written by [`MjpegWriter`].
+pub const MJPEG_BOUNDARY: &str = "esphomeframe";
+
+/// Request for a single image (`stream` false) or for a stream of images from all cameras of the device.
+pub(crate) fn image_request(stream: bool) -> api::CameraImageRequest {
+	let mut request = api::CameraImageRequest::new();
+	request.single = !stream;
+	request.stream = stream;
+	request
+}
+
+/// Reassembles the chunks of the images sent by one camera.
+pub(crate) struct ImageAssembler {
+	key: u32,
+	data: Vec<u8>,
+}
+
+impl ImageAssembler {
+	pub(crate) fn new(key: u32) -> Self {
+		ImageAssembler {
+			key,
+			data: Vec::new(),
+		}
+	}
+
+	/// Whether `event` holds a chunk of an image of this camera.
+	pub(crate) fn accepts(&self, event: &Event) -> bool {
+		matches!(event, Event::CameraImageResponse(response) if response.key == self.key)
+	}
+
+	/// Add the chunk contained in `event`, if any. Returns the image once it is complete.
+	pub(crate) fn push(&mut self, event: &Event) -> Option<Vec<u8>> {
+		match event {
+			Event::CameraImageResponse(response) if response.key == self.key => {
+				self.data.extend_from_slice(&response.data);
+				response.done.then(|| std::mem::take(&mut self.data))
+			}
+			_ => None,
+		}
+	}
+}
+
+/// Writes JPEG images as an MJPEG stream (`multipart/x-mixed-replace`), as served over HTTP or saved to a file.
+pub struct MjpegWriter<W> {
+	writer: W,
+}
+
+impl<W: Write> MjpegWriter<W> {
+	pub fn new(writer: W) -> Self {
+		MjpegWriter { writer }
+	}
+
+	/// Value of the `Content-Type` header for the stream.
+	#[must_use]
+	pub fn content_type() -> String {
+		format!("multipart/x-mixed-replace; boundary={MJPEG_BOUNDARY}")
+	}
+
+	/// Write one image as a part of the stream and flush it.
+	pub fn write_frame(&mut self, jpeg: &[u8]) -> io::Result<()> {
+		write!(
+			self.writer,
+			"--{MJPEG_BOUNDARY}\r\nContent-Type: image/jpeg\r\nContent-Length: {}\r\n\r\n",
+			jpeg.len()
+		)?;
+		self.writer.write_all(jpeg)?;
+		self.writer.write_all(b"\r\n")?;
+		self.writer.flush()
+	}
+
+	pub fn into_inner(self) -> W {
+		self.writer
+	}
+}
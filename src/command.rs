@@ -0,0 +1,1559 @@
+use crate::{api, model::State, ClimateInfo, Entity, EntityKind, EntityType, EspHomeError, Event};
+use std::{collections::HashMap, time::Duration};
+
+// Capabilities that color modes are composed of
+const CAPABILITY_BRIGHTNESS: i32 = 2;
+const CAPABILITY_WHITE: i32 = 4;
+const CAPABILITY_COLOR_TEMPERATURE: i32 = 8;
+const CAPABILITY_COLD_WARM_WHITE: i32 = 16;
+const CAPABILITY_RGB: i32 = 32;
+
+/// Effect name that turns off the active effect.
+const NO_EFFECT: &str = "None";
+
+/// Check that `entity` was listed by the device, and that it is of the expected type.
+pub(crate) fn check_entity(
+	entities: &HashMap<u32, EntityType>,
+	entity: &Entity,
+	expected: EntityType,
+) -> Result<(), EspHomeError> {
+	let key = entity.key();
+	match entities.get(&key) {
+		None => Err(EspHomeError::UnknownEntity(key)),
+		Some(&actual) if actual != expected => Err(EspHomeError::WrongEntityType {
+			key,
+			expected,
+			actual,
+		}),
+		Some(_) => Ok(()),
+	}
+}
+
+fn invalid(entity: &Entity, reason: String) -> EspHomeError {
+	EspHomeError::InvalidCommand {
+		key: entity.key(),
+		reason,
+	}
+}
+
+fn wrong_type(entity: &Entity, expected: EntityType) -> EspHomeError {
+	EspHomeError::WrongEntityType {
+		key: entity.key(),
+		expected,
+		actual: entity.entity_type(),
+	}
+}
+
+fn check_fraction(entity: &Entity, name: &str, value: f32) -> Result<(), EspHomeError> {
+	if !(0.0..=1.0).contains(&value) {
+		return Err(invalid(
+			entity,
+			format!("{name} must be between 0.0 and 1.0, got {value}"),
+		));
+	}
+	Ok(())
+}
+
+fn milliseconds(duration: Duration) -> u32 {
+	u32::try_from(duration.as_millis()).unwrap_or(u32::MAX)
+}
+
+pub(crate) fn switch_command(
+	entities: &HashMap<u32, EntityType>,
+	entity: &Entity,
+	state: bool,
+) -> Result<api::SwitchCommandRequest, EspHomeError> {
+	check_entity(entities, entity, EntityType::Switch)?;
+	let mut command = api::SwitchCommandRequest::new();
+	command.key = entity.key();
+	command.state = state;
+	Ok(command)
+}
+
+/// The inverse of the last known state of a switch.
+pub(crate) fn toggled(entity: &Entity, state: Option<&State>) -> Result<bool, EspHomeError> {
+	match state {
+		Some(State::Binary(on)) => Ok(!on),
+		_ => Err(EspHomeError::UnknownState(entity.key())),
+	}
+}
+
+/// A command for a light. Only the properties that are set are changed. The command is checked against the
+/// capabilities of the light when it is sent (see [`AuthenticatedDevice::light_command`](crate::AuthenticatedDevice::light_command)).
+#[derive(Debug, Clone, Default)]
+pub struct LightCommand {
+	command: api::LightCommandRequest,
+}
+
+impl LightCommand {
+	#[must_use]
+	pub fn new() -> LightCommand {
+		LightCommand::default()
+	}
+
+	#[must_use]
+	pub fn on(self) -> LightCommand {
+		self.state(true)
+	}
+
+	#[must_use]
+	pub fn off(self) -> LightCommand {
+		self.state(false)
+	}
+
+	#[must_use]
+	pub fn state(mut self, on: bool) -> LightCommand {
+		self.command.has_state = true;
+		self.command.state = on;
+		self
+	}
+
+	/// Brightness between 0.0 and 1.0.
+	#[must_use]
+	pub fn brightness(mut self, brightness: f32) -> LightCommand {
+		self.command.has_brightness = true;
+		self.command.brightness = brightness;
+		self
+	}
+
+	/// Switch to the given color mode, which must be one of the modes the light supports.
+	#[must_use]
+	pub fn color_mode(mut self, color_mode: api::ColorMode) -> LightCommand {
+		self.command.has_color_mode = true;
+		self.command.color_mode = color_mode.into();
+		self
+	}
+
+	/// Brightness of the RGB channels, between 0.0 and 1.0.
+	#[must_use]
+	pub fn color_brightness(mut self, color_brightness: f32) -> LightCommand {
+		self.command.has_color_brightness = true;
+		self.command.color_brightness = color_brightness;
+		self
+	}
+
+	/// Red, green and blue components, each between 0.0 and 1.0.
+	#[must_use]
+	pub fn rgb(mut self, red: f32, green: f32, blue: f32) -> LightCommand {
+		self.command.has_rgb = true;
+		self.command.red = red;
+		self.command.green = green;
+		self.command.blue = blue;
+		self
+	}
+
+	#[must_use]
+	pub fn white(mut self, white: f32) -> LightCommand {
+		self.command.has_white = true;
+		self.command.white = white;
+		self
+	}
+
+	/// Color temperature in mireds, within the range the light supports.
+	#[must_use]
+	pub fn color_temperature(mut self, mireds: f32) -> LightCommand {
+		self.command.has_color_temperature = true;
+		self.command.color_temperature = mireds;
+		self
+	}
+
+	#[must_use]
+	pub fn cold_white(mut self, cold_white: f32) -> LightCommand {
+		self.command.has_cold_white = true;
+		self.command.cold_white = cold_white;
+		self
+	}
+
+	#[must_use]
+	pub fn warm_white(mut self, warm_white: f32) -> LightCommand {
+		self.command.has_warm_white = true;
+		self.command.warm_white = warm_white;
+		self
+	}
+
+	#[must_use]
+	pub fn transition_length(mut self, transition_length: Duration) -> LightCommand {
+		self.command.has_transition_length = true;
+		self.command.transition_length = milliseconds(transition_length);
+		self
+	}
+
+	#[must_use]
+	pub fn flash_length(mut self, flash_length: Duration) -> LightCommand {
+		self.command.has_flash_length = true;
+		self.command.flash_length = milliseconds(flash_length);
+		self
+	}
+
+	/// Start one of the effects of the light, or stop the active effect with `"None"`.
+	#[must_use]
+	pub fn effect(mut self, effect: &str) -> LightCommand {
+		self.command.has_effect = true;
+		self.command.effect = effect.to_string();
+		self
+	}
+}
+
+pub(crate) fn light_command(
+	entities: &HashMap<u32, EntityType>,
+	entity: &Entity,
+	command: &LightCommand,
+) -> Result<api::LightCommandRequest, EspHomeError> {
+	check_entity(entities, entity, EntityType::Light)?;
+	let EntityKind::Light(info) = entity.kind() else {
+		return Err(wrong_type(entity, EntityType::Light));
+	};
+
+	let mut request = command.command.clone();
+	request.key = entity.key();
+
+	// Values can only be set when the requested color mode has the capability. Without a color mode, one of the
+	// supported modes must have all the capabilities the command needs.
+	let supported_modes = info.supported_color_modes();
+	let modes: Vec<i32> = if request.has_color_mode {
+		let mode = request.color_mode.enum_value_or_default();
+		if !supported_modes.contains(&mode) {
+			return Err(invalid(
+				entity,
+				format!("color mode {mode:?} is not supported (supported: {supported_modes:?})"),
+			));
+		}
+		vec![mode as i32]
+	} else {
+		supported_modes.iter().map(|mode| *mode as i32).collect()
+	};
+
+	let required = [
+		(request.has_brightness, CAPABILITY_BRIGHTNESS, "brightness"),
+		(
+			request.has_color_brightness,
+			CAPABILITY_RGB,
+			"color brightness",
+		),
+		(request.has_rgb, CAPABILITY_RGB, "RGB color"),
+		(request.has_white, CAPABILITY_WHITE, "white value"),
+		(
+			request.has_color_temperature,
+			CAPABILITY_COLOR_TEMPERATURE,
+			"color temperature",
+		),
+		(
+			request.has_cold_white,
+			CAPABILITY_COLD_WARM_WHITE,
+			"cold white",
+		),
+		(
+			request.has_warm_white,
+			CAPABILITY_COLD_WARM_WHITE,
+			"warm white",
+		),
+	];
+	let capabilities = modes.iter().fold(0, |c, mode| c | mode);
+	let mut needed = 0;
+	for (set, capability, name) in required {
+		if !set {
+			continue;
+		}
+		if capabilities & capability == 0 {
+			return Err(invalid(entity, format!("{name} is not supported")));
+		}
+		needed |= capability;
+	}
+	if needed != 0 && !modes.iter().any(|mode| mode & needed == needed) {
+		return Err(invalid(
+			entity,
+			"no supported color mode has all the requested values".to_string(),
+		));
+	}
+
+	let fractions = [
+		(request.has_brightness, "brightness", request.brightness),
+		(
+			request.has_color_brightness,
+			"color brightness",
+			request.color_brightness,
+		),
+		(request.has_rgb, "red", request.red),
+		(request.has_rgb, "green", request.green),
+		(request.has_rgb, "blue", request.blue),
+		(request.has_white, "white", request.white),
+		(request.has_cold_white, "cold white", request.cold_white),
+		(request.has_warm_white, "warm white", request.warm_white),
+	];
+	for (set, name, value) in fractions {
+		if set {
+			check_fraction(entity, name, value)?;
+		}
+	}
+
+	if request.has_color_temperature {
+		let (min, max) = (info.min_mireds(), info.max_mireds());
+		if max > 0.0 && !(min..=max).contains(&request.color_temperature) {
+			return Err(invalid(
+				entity,
+				format!(
+					"color temperature must be between {min} and {max} mireds, got {}",
+					request.color_temperature
+				),
+			));
+		}
+	}
+
+	if request.has_effect
+		&& request.effect != NO_EFFECT
+		&& !info.effects().contains(&request.effect)
+	{
+		return Err(invalid(
+			entity,
+			format!(
+				"unknown effect '{}' (available: {:?})",
+				request.effect,
+				info.effects()
+			),
+		));
+	}
+
+	Ok(request)
+}
+
+/// A command for a cover. Positions and tilts range from 0.0 (closed) to 1.0 (open).
+#[derive(Debug, Clone, Default)]
+pub struct CoverCommand {
+	command: api::CoverCommandRequest,
+}
+
+impl CoverCommand {
+	#[must_use]
+	pub fn new() -> CoverCommand {
+		CoverCommand::default()
+	}
+
+	#[must_use]
+	pub fn open() -> CoverCommand {
+		CoverCommand::new().position(COVER_OPEN)
+	}
+
+	#[must_use]
+	pub fn close() -> CoverCommand {
+		CoverCommand::new().position(COVER_CLOSED)
+	}
+
+	#[must_use]
+	pub fn stop() -> CoverCommand {
+		let mut command = CoverCommand::new();
+		command.command.stop = true;
+		command
+	}
+
+	/// Move to the given position. Intermediate positions require a cover that supports them.
+	#[must_use]
+	pub fn position(mut self, position: f32) -> CoverCommand {
+		self.command.has_position = true;
+		self.command.position = position;
+		self
+	}
+
+	#[must_use]
+	pub fn tilt(mut self, tilt: f32) -> CoverCommand {
+		self.command.has_tilt = true;
+		self.command.tilt = tilt;
+		self
+	}
+}
+
+const COVER_OPEN: f32 = 1.0;
+const COVER_CLOSED: f32 = 0.0;
+
+/// Build the request for a cover command. Devices with an API version older than 1.1 only understand the legacy
+/// open, close and stop commands.
+#[allow(clippy::float_cmp)]
+pub(crate) fn cover_command(
+	entities: &HashMap<u32, EntityType>,
+	entity: &Entity,
+	command: &CoverCommand,
+	api_version: (u32, u32),
+) -> Result<api::CoverCommandRequest, EspHomeError> {
+	check_entity(entities, entity, EntityType::Cover)?;
+	let EntityKind::Cover(info) = entity.kind() else {
+		return Err(wrong_type(entity, EntityType::Cover));
+	};
+
+	let mut request = command.command.clone();
+	request.key = entity.key();
+
+	if request.has_position {
+		check_fraction(entity, "position", request.position)?;
+		let fully = request.position == COVER_OPEN || request.position == COVER_CLOSED;
+		if !fully && !info.supports_position() {
+			return Err(invalid(
+				entity,
+				"setting the position is not supported".to_string(),
+			));
+		}
+	}
+	if request.has_tilt {
+		check_fraction(entity, "tilt", request.tilt)?;
+		if !info.supports_tilt() {
+			return Err(invalid(entity, "tilting is not supported".to_string()));
+		}
+	}
+
+	if api_version >= (1, 1) {
+		return Ok(request);
+	}
+
+	let legacy_command = if request.stop {
+		api::LegacyCoverCommand::LEGACY_COVER_COMMAND_STOP
+	} else if request.has_position && request.position == COVER_OPEN && !request.has_tilt {
+		api::LegacyCoverCommand::LEGACY_COVER_COMMAND_OPEN
+	} else if request.has_position && request.position == COVER_CLOSED && !request.has_tilt {
+		api::LegacyCoverCommand::LEGACY_COVER_COMMAND_CLOSE
+	} else {
+		return Err(invalid(
+			entity,
+			"the device only supports opening, closing and stopping".to_string(),
+		));
+	};
+
+	let mut legacy = api::CoverCommandRequest::new();
+	legacy.key = entity.key();
+	legacy.has_legacy_command = true;
+	legacy.legacy_command = legacy_command.into();
+	Ok(legacy)
+}
+
+/// Whether the event is a state update for the cover.
+pub(crate) fn is_cover_state(entity: &Entity, event: &Event) -> bool {
+	matches!(event, Event::CoverStateResponse(state) if state.key == entity.key())
+}
+
+/// Whether the event is a state update reporting that the cover is idle.
+pub(crate) fn is_cover_idle(entity: &Entity, event: &Event) -> bool {
+	matches!(event, Event::CoverStateResponse(state)
+		if state.key == entity.key()
+			&& state.current_operation.enum_value_or_default() == api::CoverOperation::COVER_OPERATION_IDLE)
+}
+
+/// A command for a fan.
+#[derive(Debug, Clone, Default)]
+pub struct FanCommand {
+	command: api::FanCommandRequest,
+}
+
+impl FanCommand {
+	#[must_use]
+	pub fn new() -> FanCommand {
+		FanCommand::default()
+	}
+
+	#[must_use]
+	pub fn on(self) -> FanCommand {
+		self.state(true)
+	}
+
+	#[must_use]
+	pub fn off(self) -> FanCommand {
+		self.state(false)
+	}
+
+	#[must_use]
+	pub fn state(mut self, on: bool) -> FanCommand {
+		self.command.has_state = true;
+		self.command.state = on;
+		self
+	}
+
+	/// Speed between 1 and the number of speeds the fan supports.
+	#[must_use]
+	pub fn speed_level(mut self, speed_level: i32) -> FanCommand {
+		self.command.has_speed_level = true;
+		self.command.speed_level = speed_level;
+		self
+	}
+
+	#[must_use]
+	pub fn oscillating(mut self, oscillating: bool) -> FanCommand {
+		self.command.has_oscillating = true;
+		self.command.oscillating = oscillating;
+		self
+	}
+
+	#[must_use]
+	pub fn direction(mut self, direction: api::FanDirection) -> FanCommand {
+		self.command.has_direction = true;
+		self.command.direction = direction.into();
+		self
+	}
+}
+
+/// Number of speeds of fans on devices that predate speed levels.
+const LEGACY_FAN_SPEED_COUNT: i32 = 3;
+
+/// Build the request for a fan command. Devices with an API version older than 1.4 do not know speed levels, and are
+/// sent the low, medium or high speed instead.
+pub(crate) fn fan_command(
+	entities: &HashMap<u32, EntityType>,
+	entity: &Entity,
+	command: &FanCommand,
+	api_version: (u32, u32),
+) -> Result<api::FanCommandRequest, EspHomeError> {
+	check_entity(entities, entity, EntityType::Fan)?;
+	let EntityKind::Fan(info) = entity.kind() else {
+		return Err(wrong_type(entity, EntityType::Fan));
+	};
+
+	let mut request = command.command.clone();
+	request.key = entity.key();
+
+	if request.has_oscillating && !info.supports_oscillation() {
+		return Err(invalid(entity, "oscillation is not supported".to_string()));
+	}
+	if request.has_direction && !info.supports_direction() {
+		return Err(invalid(
+			entity,
+			"setting the direction is not supported".to_string(),
+		));
+	}
+	if !request.has_speed_level {
+		return Ok(request);
+	}
+
+	if !info.supports_speed() {
+		return Err(invalid(
+			entity,
+			"setting the speed is not supported".to_string(),
+		));
+	}
+	let speed_count = match info.supported_speed_count() {
+		0 => LEGACY_FAN_SPEED_COUNT,
+		count => count,
+	};
+	if !(1..=speed_count).contains(&request.speed_level) {
+		return Err(invalid(
+			entity,
+			format!(
+				"speed level must be between 1 and {speed_count}, got {}",
+				request.speed_level
+			),
+		));
+	}
+
+	if api_version < (1, 4) {
+		// Scale the level to the three legacy speeds
+		let speed =
+			match (request.speed_level * LEGACY_FAN_SPEED_COUNT + speed_count - 1) / speed_count {
+				1 => api::FanSpeed::FAN_SPEED_LOW,
+				2 => api::FanSpeed::FAN_SPEED_MEDIUM,
+				_ => api::FanSpeed::FAN_SPEED_HIGH,
+			};
+		request.has_speed_level = false;
+		request.speed_level = 0;
+		request.has_speed = true;
+		request.speed = speed.into();
+	}
+	Ok(request)
+}
+
+/// A command for a climate device. Temperatures are in degrees Celsius.
+#[derive(Debug, Clone, Default)]
+pub struct ClimateCommand {
+	command: api::ClimateCommandRequest,
+}
+
+impl ClimateCommand {
+	#[must_use]
+	pub fn new() -> ClimateCommand {
+		ClimateCommand::default()
+	}
+
+	#[must_use]
+	pub fn mode(mut self, mode: api::ClimateMode) -> ClimateCommand {
+		self.command.has_mode = true;
+		self.command.mode = mode.into();
+		self
+	}
+
+	/// Target temperature, for devices that do not use a two-point target temperature.
+	#[must_use]
+	pub fn target_temperature(mut self, temperature: f32) -> ClimateCommand {
+		self.command.has_target_temperature = true;
+		self.command.target_temperature = temperature;
+		self
+	}
+
+	/// Lower and upper target temperature, for devices that use a two-point target temperature.
+	#[must_use]
+	pub fn target_temperature_range(mut self, low: f32, high: f32) -> ClimateCommand {
+		self.command.has_target_temperature_low = true;
+		self.command.target_temperature_low = low;
+		self.command.has_target_temperature_high = true;
+		self.command.target_temperature_high = high;
+		self
+	}
+
+	#[must_use]
+	pub fn fan_mode(mut self, fan_mode: api::ClimateFanMode) -> ClimateCommand {
+		self.command.has_fan_mode = true;
+		self.command.fan_mode = fan_mode.into();
+		self
+	}
+
+	#[must_use]
+	pub fn swing_mode(mut self, swing_mode: api::ClimateSwingMode) -> ClimateCommand {
+		self.command.has_swing_mode = true;
+		self.command.swing_mode = swing_mode.into();
+		self
+	}
+
+	#[must_use]
+	pub fn custom_fan_mode(mut self, custom_fan_mode: &str) -> ClimateCommand {
+		self.command.has_custom_fan_mode = true;
+		self.command.custom_fan_mode = custom_fan_mode.to_string();
+		self
+	}
+
+	#[must_use]
+	pub fn preset(mut self, preset: api::ClimatePreset) -> ClimateCommand {
+		self.command.has_preset = true;
+		self.command.preset = preset.into();
+		self
+	}
+
+	#[must_use]
+	pub fn custom_preset(mut self, custom_preset: &str) -> ClimateCommand {
+		self.command.has_custom_preset = true;
+		self.command.custom_preset = custom_preset.to_string();
+		self
+	}
+}
+
+fn check_supported<T>(
+	entity: &Entity,
+	name: &str,
+	value: &T,
+	supported: &[T],
+) -> Result<(), EspHomeError>
+where
+	T: PartialEq + std::fmt::Debug,
+{
+	if !supported.contains(value) {
+		return Err(invalid(
+			entity,
+			format!("{name} {value:?} is not supported (supported: {supported:?})"),
+		));
+	}
+	Ok(())
+}
+
+fn check_temperature(
+	entity: &Entity,
+	info: &ClimateInfo,
+	temperature: f32,
+) -> Result<(), EspHomeError> {
+	let (min, max) = (info.visual_min_temperature(), info.visual_max_temperature());
+	if max > min && !(min..=max).contains(&temperature) {
+		return Err(invalid(
+			entity,
+			format!("temperature must be between {min} and {max}, got {temperature}"),
+		));
+	}
+
+	let step = info.visual_temperature_step();
+	if step > 0.0 && ((temperature / step).round() * step - temperature).abs() > 0.001 {
+		return Err(invalid(
+			entity,
+			format!("temperature must be a multiple of {step}, got {temperature}"),
+		));
+	}
+	Ok(())
+}
+
+/// Build the request for a climate command. Devices with an API version older than 1.5 do not know presets, and are
+/// sent the away flag instead.
+pub(crate) fn climate_command(
+	entities: &HashMap<u32, EntityType>,
+	entity: &Entity,
+	command: &ClimateCommand,
+	api_version: (u32, u32),
+) -> Result<api::ClimateCommandRequest, EspHomeError> {
+	check_entity(entities, entity, EntityType::Climate)?;
+	let EntityKind::Climate(info) = entity.kind() else {
+		return Err(wrong_type(entity, EntityType::Climate));
+	};
+
+	let mut request = command.command.clone();
+	request.key = entity.key();
+
+	if request.has_mode {
+		let mode = request.mode.enum_value_or_default();
+		check_supported(entity, "mode", &mode, &info.supported_modes())?;
+	}
+
+	let two_point = info.supports_two_point_target_temperature();
+	if request.has_target_temperature {
+		if two_point {
+			return Err(invalid(
+				entity,
+				"the device uses a two-point target temperature".to_string(),
+			));
+		}
+		check_temperature(entity, info, request.target_temperature)?;
+	}
+	if request.has_target_temperature_low || request.has_target_temperature_high {
+		if !two_point {
+			return Err(invalid(
+				entity,
+				"the device does not use a two-point target temperature".to_string(),
+			));
+		}
+		check_temperature(entity, info, request.target_temperature_low)?;
+		check_temperature(entity, info, request.target_temperature_high)?;
+		if request.target_temperature_low > request.target_temperature_high {
+			return Err(invalid(
+				entity,
+				"the low target temperature is above the high target temperature".to_string(),
+			));
+		}
+	}
+
+	if request.has_fan_mode {
+		let fan_mode = request.fan_mode.enum_value_or_default();
+		check_supported(entity, "fan mode", &fan_mode, &info.supported_fan_modes())?;
+	}
+	if request.has_swing_mode {
+		let swing_mode = request.swing_mode.enum_value_or_default();
+		check_supported(
+			entity,
+			"swing mode",
+			&swing_mode,
+			&info.supported_swing_modes(),
+		)?;
+	}
+	if request.has_custom_fan_mode {
+		check_supported(
+			entity,
+			"custom fan mode",
+			&request.custom_fan_mode,
+			info.supported_custom_fan_modes(),
+		)?;
+	}
+	if request.has_custom_preset {
+		check_supported(
+			entity,
+			"custom preset",
+			&request.custom_preset,
+			info.supported_custom_presets(),
+		)?;
+	}
+	if !request.has_preset {
+		return Ok(request);
+	}
+
+	// Older firmware only reports whether away mode is supported
+	let preset = request.preset.enum_value_or_default();
+	let mut supported_presets = info.supported_presets();
+	if supported_presets.is_empty() && info.legacy_supports_away() {
+		supported_presets = vec![
+			api::ClimatePreset::CLIMATE_PRESET_HOME,
+			api::ClimatePreset::CLIMATE_PRESET_AWAY,
+		];
+	}
+	check_supported(entity, "preset", &preset, &supported_presets)?;
+
+	if api_version < (1, 5) {
+		let away = match preset {
+			api::ClimatePreset::CLIMATE_PRESET_AWAY => true,
+			api::ClimatePreset::CLIMATE_PRESET_HOME => false,
+			_ => {
+				return Err(invalid(
+					entity,
+					format!("preset {preset:?} is not supported by the device's API version"),
+				))
+			}
+		};
+		request.has_preset = false;
+		request.preset = api::ClimatePreset::CLIMATE_PRESET_NONE.into();
+		request.has_legacy_away = true;
+		request.legacy_away = away;
+	}
+	Ok(request)
+}
+
+/// Build the request that sets a number. The value is snapped to the step of the number. Values outside of its range
+/// are clamped when `clamp` is set, and rejected otherwise.
+pub(crate) fn number_command(
+	entities: &HashMap<u32, EntityType>,
+	entity: &Entity,
+	value: f32,
+	clamp: bool,
+) -> Result<api::NumberCommandRequest, EspHomeError> {
+	check_entity(entities, entity, EntityType::Number)?;
+	let EntityKind::Number(info) = entity.kind() else {
+		return Err(wrong_type(entity, EntityType::Number));
+	};
+
+	let (min, max) = (info.min_value(), info.max_value());
+	if min.is_nan() || max.is_nan() || min > max {
+		return Err(invalid(
+			entity,
+			format!("the device reported an invalid range ({min} to {max})"),
+		));
+	}
+	if value.is_nan() || (!clamp && !(min..=max).contains(&value)) {
+		return Err(invalid(
+			entity,
+			format!("value must be between {min} and {max}, got {value}"),
+		));
+	}
+
+	let mut value = value.clamp(min, max);
+	let step = info.step();
+	if step > 0.0 {
+		value = (min + ((value - min) / step).round() * step).min(max);
+	}
+
+	let mut request = api::NumberCommandRequest::new();
+	request.key = entity.key();
+	request.state = value;
+	Ok(request)
+}
+
+pub(crate) fn select_command(
+	entities: &HashMap<u32, EntityType>,
+	entity: &Entity,
+	option: &str,
+) -> Result<api::SelectCommandRequest, EspHomeError> {
+	check_entity(entities, entity, EntityType::Select)?;
+	let EntityKind::Select(info) = entity.kind() else {
+		return Err(wrong_type(entity, EntityType::Select));
+	};
+
+	if !info.options().iter().any(|o| o == option) {
+		return Err(invalid(
+			entity,
+			format!(
+				"unknown option '{option}' (valid options: {})",
+				info.options().join(", ")
+			),
+		));
+	}
+
+	let mut request = api::SelectCommandRequest::new();
+	request.key = entity.key();
+	request.state = option.to_string();
+	Ok(request)
+}
+
+pub(crate) fn button_command(
+	entities: &HashMap<u32, EntityType>,
+	entity: &Entity,
+) -> Result<api::ButtonCommandRequest, EspHomeError> {
+	check_entity(entities, entity, EntityType::Button)?;
+	let mut request = api::ButtonCommandRequest::new();
+	request.key = entity.key();
+	Ok(request)
+}
+
+pub(crate) fn lock_command(
+	entities: &HashMap<u32, EntityType>,
+	entity: &Entity,
+	command: api::LockCommand,
+	code: Option<&str>,
+) -> Result<api::LockCommandRequest, EspHomeError> {
+	check_entity(entities, entity, EntityType::Lock)?;
+	let EntityKind::Lock(info) = entity.kind() else {
+		return Err(wrong_type(entity, EntityType::Lock));
+	};
+
+	if command == api::LockCommand::LOCK_OPEN && !info.supports_open() {
+		return Err(invalid(entity, "opening is not supported".to_string()));
+	}
+	if code.is_none() && info.requires_code() {
+		return Err(invalid(entity, "a code is required".to_string()));
+	}
+
+	let mut request = api::LockCommandRequest::new();
+	request.key = entity.key();
+	request.command = command.into();
+	if let Some(code) = code {
+		request.has_code = true;
+		request.code = code.to_string();
+	}
+	Ok(request)
+}
+
+/// A command for a media player.
+#[derive(Debug, Clone, Default)]
+pub struct MediaPlayerCommand {
+	command: api::MediaPlayerCommandRequest,
+}
+
+impl MediaPlayerCommand {
+	#[must_use]
+	pub fn new() -> MediaPlayerCommand {
+		MediaPlayerCommand::default()
+	}
+
+	#[must_use]
+	pub fn play() -> MediaPlayerCommand {
+		MediaPlayerCommand::new().command(api::MediaPlayerCommand::MEDIA_PLAYER_COMMAND_PLAY)
+	}
+
+	/// Pause playback. Requires a media player that supports pausing.
+	#[must_use]
+	pub fn pause() -> MediaPlayerCommand {
+		MediaPlayerCommand::new().command(api::MediaPlayerCommand::MEDIA_PLAYER_COMMAND_PAUSE)
+	}
+
+	#[must_use]
+	pub fn stop() -> MediaPlayerCommand {
+		MediaPlayerCommand::new().command(api::MediaPlayerCommand::MEDIA_PLAYER_COMMAND_STOP)
+	}
+
+	#[must_use]
+	pub fn mute() -> MediaPlayerCommand {
+		MediaPlayerCommand::new().command(api::MediaPlayerCommand::MEDIA_PLAYER_COMMAND_MUTE)
+	}
+
+	#[must_use]
+	pub fn unmute() -> MediaPlayerCommand {
+		MediaPlayerCommand::new().command(api::MediaPlayerCommand::MEDIA_PLAYER_COMMAND_UNMUTE)
+	}
+
+	#[must_use]
+	pub fn command(mut self, command: api::MediaPlayerCommand) -> MediaPlayerCommand {
+		self.command.has_command = true;
+		self.command.command = command.into();
+		self
+	}
+
+	/// Volume between 0.0 and 1.0.
+	#[must_use]
+	pub fn volume(mut self, volume: f32) -> MediaPlayerCommand {
+		self.command.has_volume = true;
+		self.command.volume = volume;
+		self
+	}
+
+	/// Play the media at the given URL.
+	#[must_use]
+	pub fn media_url(mut self, media_url: &str) -> MediaPlayerCommand {
+		self.command.has_media_url = true;
+		self.command.media_url = media_url.to_string();
+		self
+	}
+}
+
+pub(crate) fn media_player_command(
+	entities: &HashMap<u32, EntityType>,
+	entity: &Entity,
+	command: &MediaPlayerCommand,
+) -> Result<api::MediaPlayerCommandRequest, EspHomeError> {
+	check_entity(entities, entity, EntityType::MediaPlayer)?;
+	let EntityKind::MediaPlayer(info) = entity.kind() else {
+		return Err(wrong_type(entity, EntityType::MediaPlayer));
+	};
+
+	let mut request = command.command.clone();
+	request.key = entity.key();
+
+	if request.has_command
+		&& request.command.enum_value_or_default()
+			== api::MediaPlayerCommand::MEDIA_PLAYER_COMMAND_PAUSE
+		&& !info.supports_pause()
+	{
+		return Err(invalid(entity, "pausing is not supported".to_string()));
+	}
+	if request.has_volume {
+		check_fraction(entity, "volume", request.volume)?;
+	}
+	Ok(request)
+}
+
+/// Value of an argument of a user-defined service.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ServiceValue {
+	Bool(bool),
+	Int(i32),
+	Float(f32),
+	String(String),
+	BoolArray(Vec<bool>),
+	IntArray(Vec<i32>),
+	FloatArray(Vec<f32>),
+	StringArray(Vec<String>),
+}
+
+impl ServiceValue {
+	#[must_use]
+	pub fn arg_type(&self) -> api::ServiceArgType {
+		match self {
+			ServiceValue::Bool(_) => api::ServiceArgType::SERVICE_ARG_TYPE_BOOL,
+			ServiceValue::Int(_) => api::ServiceArgType::SERVICE_ARG_TYPE_INT,
+			ServiceValue::Float(_) => api::ServiceArgType::SERVICE_ARG_TYPE_FLOAT,
+			ServiceValue::String(_) => api::ServiceArgType::SERVICE_ARG_TYPE_STRING,
+			ServiceValue::BoolArray(_) => api::ServiceArgType::SERVICE_ARG_TYPE_BOOL_ARRAY,
+			ServiceValue::IntArray(_) => api::ServiceArgType::SERVICE_ARG_TYPE_INT_ARRAY,
+			ServiceValue::FloatArray(_) => api::ServiceArgType::SERVICE_ARG_TYPE_FLOAT_ARRAY,
+			ServiceValue::StringArray(_) => api::ServiceArgType::SERVICE_ARG_TYPE_STRING_ARRAY,
+		}
+	}
+
+	/// Encode the value. Devices with an API version older than 1.3 expect integers in `legacy_int` (a plain `int32`,
+	/// unlike the zigzag `sint32` `int_`).
+	fn to_argument(&self, api_version: (u32, u32)) -> api::ExecuteServiceArgument {
+		let mut argument = api::ExecuteServiceArgument::new();
+		match self {
+			ServiceValue::Bool(v) => argument.bool_ = *v,
+			ServiceValue::Int(v) if api_version < (1, 3) => argument.legacy_int = *v,
+			ServiceValue::Int(v) => argument.int_ = *v,
+			ServiceValue::Float(v) => argument.float_ = *v,
+			ServiceValue::String(v) => argument.string_.clone_from(v),
+			ServiceValue::BoolArray(v) => argument.bool_array.clone_from(v),
+			ServiceValue::IntArray(v) => argument.int_array.clone_from(v),
+			ServiceValue::FloatArray(v) => argument.float_array.clone_from(v),
+			ServiceValue::StringArray(v) => argument.string_array.clone_from(v),
+		}
+		argument
+	}
+}
+
+/// Build the request that executes a user-defined service. The arguments are checked against the arguments the
+/// service was listed with.
+pub(crate) fn execute_service_command(
+	entities: &HashMap<u32, EntityType>,
+	entity: &Entity,
+	arguments: &[ServiceValue],
+	api_version: (u32, u32),
+) -> Result<api::ExecuteServiceRequest, EspHomeError> {
+	check_entity(entities, entity, EntityType::Services)?;
+	let EntityKind::Services(info) = entity.kind() else {
+		return Err(wrong_type(entity, EntityType::Services));
+	};
+
+	let expected = info.arguments();
+	if arguments.len() != expected.len() {
+		return Err(invalid(
+			entity,
+			format!(
+				"expected {} arguments ({expected:?}), got {}",
+				expected.len(),
+				arguments.len()
+			),
+		));
+	}
+
+	let mut request = api::ExecuteServiceRequest::new();
+	request.key = entity.key();
+	for ((name, arg_type), value) in expected.into_iter().zip(arguments) {
+		if value.arg_type() != arg_type {
+			return Err(invalid(
+				entity,
+				format!(
+					"argument '{name}' must be of type {arg_type:?}, got {:?}",
+					value.arg_type()
+				),
+			));
+		}
+		request.args.push(value.to_argument(api_version));
+	}
+	Ok(request)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::model::EntityInfo;
+
+	/// An entity listed by the device, along with the entity types the device listed.
+	fn listed(key: u32, kind: EntityKind) -> (HashMap<u32, EntityType>, Entity) {
+		let entity = Entity::new(
+			EntityInfo {
+				name: "test".to_string(),
+				key,
+			},
+			kind,
+		);
+		(HashMap::from([(key, entity.entity_type())]), entity)
+	}
+
+	#[allow(clippy::needless_pass_by_value)]
+	fn assert_invalid<T: std::fmt::Debug>(result: Result<T, EspHomeError>) {
+		assert!(
+			matches!(result, Err(EspHomeError::InvalidCommand { .. })),
+			"{result:?}"
+		);
+	}
+
+	fn light(modes: &[api::ColorMode]) -> (HashMap<u32, EntityType>, Entity) {
+		let mut info = api::ListEntitiesLightResponse::new();
+		info.supported_color_modes = modes.iter().map(|m| (*m).into()).collect();
+		info.min_mireds = 153.0;
+		info.max_mireds = 500.0;
+		info.effects = vec!["Rainbow".to_string()];
+		listed(1, EntityKind::Light(info.into()))
+	}
+
+	#[test]
+	fn light_capabilities() {
+		let (entities, rgb) = light(&[api::ColorMode::COLOR_MODE_RGB]);
+		let command = LightCommand::new().on().brightness(0.5).rgb(1.0, 0.0, 0.2);
+		let request = light_command(&entities, &rgb, &command).unwrap();
+		assert_eq!(request.key, 1);
+		assert!(request.has_rgb && request.has_brightness);
+
+		assert_invalid(light_command(
+			&entities,
+			&rgb,
+			&LightCommand::new().white(1.0),
+		));
+		assert_invalid(light_command(
+			&entities,
+			&rgb,
+			&LightCommand::new().color_temperature(300.0),
+		));
+		assert_invalid(light_command(
+			&entities,
+			&rgb,
+			&LightCommand::new().color_mode(api::ColorMode::COLOR_MODE_WHITE),
+		));
+	}
+
+	#[test]
+	fn light_capabilities_of_color_mode() {
+		let (entities, lamp) = light(&[
+			api::ColorMode::COLOR_MODE_RGB,
+			api::ColorMode::COLOR_MODE_COLOR_TEMPERATURE,
+		]);
+		let command = LightCommand::new().color_temperature(300.0);
+		light_command(&entities, &lamp, &command).unwrap();
+		assert_invalid(light_command(
+			&entities,
+			&lamp,
+			&command.clone().color_mode(api::ColorMode::COLOR_MODE_RGB),
+		));
+
+		// Without a color mode, a single supported mode must have all the values
+		assert_invalid(light_command(&entities, &lamp, &command.rgb(1.0, 0.0, 0.0)));
+		let (entities, on_off) = light(&[]);
+		light_command(&entities, &on_off, &LightCommand::new().on()).unwrap();
+	}
+
+	#[test]
+	fn light_values() {
+		let (entities, light) = light(&[api::ColorMode::COLOR_MODE_RGB_COLOR_TEMPERATURE]);
+		for mireds in [153.0, 500.0] {
+			let command = LightCommand::new().color_temperature(mireds);
+			light_command(&entities, &light, &command).unwrap();
+		}
+		for mireds in [152.0, 501.0] {
+			let command = LightCommand::new().color_temperature(mireds);
+			assert_invalid(light_command(&entities, &light, &command));
+		}
+		assert_invalid(light_command(
+			&entities,
+			&light,
+			&LightCommand::new().brightness(1.5),
+		));
+		assert_invalid(light_command(
+			&entities,
+			&light,
+			&LightCommand::new().rgb(0.0, -0.1, 0.0),
+		));
+
+		light_command(&entities, &light, &LightCommand::new().effect("Rainbow")).unwrap();
+		light_command(&entities, &light, &LightCommand::new().effect(NO_EFFECT)).unwrap();
+		assert_invalid(light_command(
+			&entities,
+			&light,
+			&LightCommand::new().effect("Strobe"),
+		));
+	}
+
+	#[test]
+	fn legacy_light_color_modes() {
+		let mut info = api::ListEntitiesLightResponse::new();
+		info.legacy_supports_brightness = true;
+		info.legacy_supports_rgb = true;
+		info.legacy_supports_color_temperature = true;
+		info.max_mireds = 500.0;
+		let (entities, light) = listed(2, EntityKind::Light(info.into()));
+		let command = LightCommand::new()
+			.rgb(1.0, 1.0, 1.0)
+			.color_temperature(200.0);
+		light_command(&entities, &light, &command).unwrap();
+		assert_invalid(light_command(
+			&entities,
+			&light,
+			&LightCommand::new().cold_white(0.5),
+		));
+	}
+
+	#[test]
+	fn wrong_entity() {
+		let (entities, light) = light(&[api::ColorMode::COLOR_MODE_ON_OFF]);
+		assert!(matches!(
+			switch_command(&entities, &light, true),
+			Err(EspHomeError::WrongEntityType { .. })
+		));
+		assert!(matches!(
+			light_command(&HashMap::new(), &light, &LightCommand::new().on()),
+			Err(EspHomeError::UnknownEntity(1))
+		));
+	}
+
+	fn cover(supports_position: bool, supports_tilt: bool) -> (HashMap<u32, EntityType>, Entity) {
+		let mut info = api::ListEntitiesCoverResponse::new();
+		info.supports_position = supports_position;
+		info.supports_tilt = supports_tilt;
+		listed(2, EntityKind::Cover(info.into()))
+	}
+
+	#[test]
+	fn cover_capabilities() {
+		let (entities, blind) = cover(true, true);
+		let command = CoverCommand::new().position(0.5).tilt(0.25);
+		let request = cover_command(&entities, &blind, &command, (1, 1)).unwrap();
+		assert_eq!(request.key, 2);
+		assert!(request.has_position && request.has_tilt && !request.has_legacy_command);
+		assert_invalid(cover_command(
+			&entities,
+			&blind,
+			&CoverCommand::new().position(1.5),
+			(1, 1),
+		));
+
+		let (entities, door) = cover(false, false);
+		assert!(cover_command(&entities, &door, &CoverCommand::open(), (1, 1)).is_ok());
+		assert_invalid(cover_command(
+			&entities,
+			&door,
+			&CoverCommand::new().position(0.5),
+			(1, 1),
+		));
+		assert_invalid(cover_command(
+			&entities,
+			&door,
+			&CoverCommand::new().tilt(0.5),
+			(1, 1),
+		));
+	}
+
+	#[test]
+	fn legacy_cover_commands() {
+		let (entities, blind) = cover(true, true);
+		for (command, expected) in [
+			(
+				CoverCommand::open(),
+				api::LegacyCoverCommand::LEGACY_COVER_COMMAND_OPEN,
+			),
+			(
+				CoverCommand::close(),
+				api::LegacyCoverCommand::LEGACY_COVER_COMMAND_CLOSE,
+			),
+			(
+				CoverCommand::stop(),
+				api::LegacyCoverCommand::LEGACY_COVER_COMMAND_STOP,
+			),
+		] {
+			let request = cover_command(&entities, &blind, &command, (1, 0)).unwrap();
+			assert!(request.has_legacy_command && !request.has_position && !request.stop);
+			assert_eq!(request.legacy_command.enum_value(), Ok(expected));
+		}
+		assert_invalid(cover_command(
+			&entities,
+			&blind,
+			&CoverCommand::new().position(0.5),
+			(1, 0),
+		));
+		assert_invalid(cover_command(
+			&entities,
+			&blind,
+			&CoverCommand::open().tilt(1.0),
+			(1, 0),
+		));
+	}
+
+	fn fan_with_speeds(speed_count: i32) -> (HashMap<u32, EntityType>, Entity) {
+		let mut info = api::ListEntitiesFanResponse::new();
+		info.supports_speed = true;
+		info.supported_speed_count = speed_count;
+		listed(3, EntityKind::Fan(info.into()))
+	}
+
+	fn legacy_speed(speed_count: i32, speed_level: i32) -> api::FanSpeed {
+		let (entities, fan) = fan_with_speeds(speed_count);
+		let command = FanCommand::new().speed_level(speed_level);
+		let request = fan_command(&entities, &fan, &command, (1, 3)).unwrap();
+		assert!(request.has_speed && !request.has_speed_level);
+		request.speed.enum_value().unwrap()
+	}
+
+	#[test]
+	fn fan_speed_levels() {
+		let (entities, fan) = fan_with_speeds(10);
+		let request =
+			fan_command(&entities, &fan, &FanCommand::new().speed_level(10), (1, 4)).unwrap();
+		assert!(request.has_speed_level && !request.has_speed);
+		assert_eq!(request.speed_level, 10);
+		for level in [0, 11] {
+			let command = FanCommand::new().speed_level(level);
+			assert_invalid(fan_command(&entities, &fan, &command, (1, 4)));
+		}
+		assert_invalid(fan_command(
+			&entities,
+			&fan,
+			&FanCommand::new().oscillating(true),
+			(1, 4),
+		));
+
+		let (entities, legacy) = fan_with_speeds(0);
+		fan_command(
+			&entities,
+			&legacy,
+			&FanCommand::new().speed_level(3),
+			(1, 4),
+		)
+		.unwrap();
+		assert_invalid(fan_command(
+			&entities,
+			&legacy,
+			&FanCommand::new().speed_level(4),
+			(1, 4),
+		));
+	}
+
+	#[test]
+	fn legacy_fan_speeds() {
+		use api::FanSpeed::{FAN_SPEED_HIGH, FAN_SPEED_LOW, FAN_SPEED_MEDIUM};
+		assert_eq!(legacy_speed(3, 1), FAN_SPEED_LOW);
+		assert_eq!(legacy_speed(3, 2), FAN_SPEED_MEDIUM);
+		assert_eq!(legacy_speed(3, 3), FAN_SPEED_HIGH);
+		assert_eq!(legacy_speed(0, 2), FAN_SPEED_MEDIUM);
+		assert_eq!(legacy_speed(10, 1), FAN_SPEED_LOW);
+		assert_eq!(legacy_speed(10, 3), FAN_SPEED_LOW);
+		assert_eq!(legacy_speed(10, 4), FAN_SPEED_MEDIUM);
+		assert_eq!(legacy_speed(10, 6), FAN_SPEED_MEDIUM);
+		assert_eq!(legacy_speed(10, 7), FAN_SPEED_HIGH);
+		assert_eq!(legacy_speed(10, 10), FAN_SPEED_HIGH);
+	}
+
+	fn thermostat(two_point: bool) -> (HashMap<u32, EntityType>, Entity) {
+		let mut info = api::ListEntitiesClimateResponse::new();
+		info.supports_two_point_target_temperature = two_point;
+		info.supported_modes = vec![
+			api::ClimateMode::CLIMATE_MODE_OFF.into(),
+			api::ClimateMode::CLIMATE_MODE_HEAT.into(),
+		];
+		info.visual_min_temperature = 10.0;
+		info.visual_max_temperature = 30.0;
+		info.visual_temperature_step = 0.5;
+		info.legacy_supports_away = true;
+		listed(4, EntityKind::Climate(info.into()))
+	}
+
+	#[test]
+	fn climate_temperatures() {
+		let (entities, climate) = thermostat(false);
+		let command = ClimateCommand::new()
+			.mode(api::ClimateMode::CLIMATE_MODE_HEAT)
+			.target_temperature(21.5);
+		climate_command(&entities, &climate, &command, (1, 5)).unwrap();
+		for temperature in [9.5, 30.5, 21.2] {
+			let command = ClimateCommand::new().target_temperature(temperature);
+			assert_invalid(climate_command(&entities, &climate, &command, (1, 5)));
+		}
+		for command in [
+			ClimateCommand::new().mode(api::ClimateMode::CLIMATE_MODE_COOL),
+			ClimateCommand::new().target_temperature_range(18.0, 22.0),
+		] {
+			assert_invalid(climate_command(&entities, &climate, &command, (1, 5)));
+		}
+
+		let (entities, two_point) = thermostat(true);
+		let command = ClimateCommand::new().target_temperature_range(18.0, 22.0);
+		climate_command(&entities, &two_point, &command, (1, 5)).unwrap();
+		for command in [
+			ClimateCommand::new().target_temperature_range(22.0, 18.0),
+			ClimateCommand::new().target_temperature(20.0),
+		] {
+			assert_invalid(climate_command(&entities, &two_point, &command, (1, 5)));
+		}
+	}
+
+	#[test]
+	fn climate_presets() {
+		let (entities, climate) = thermostat(false);
+		let away = ClimateCommand::new().preset(api::ClimatePreset::CLIMATE_PRESET_AWAY);
+		let request = climate_command(&entities, &climate, &away, (1, 5)).unwrap();
+		assert!(request.has_preset && !request.has_legacy_away);
+		assert_invalid(climate_command(
+			&entities,
+			&climate,
+			&ClimateCommand::new().preset(api::ClimatePreset::CLIMATE_PRESET_ECO),
+			(1, 5),
+		));
+
+		let request = climate_command(&entities, &climate, &away, (1, 4)).unwrap();
+		assert!(!request.has_preset && request.has_legacy_away && request.legacy_away);
+		let home = ClimateCommand::new().preset(api::ClimatePreset::CLIMATE_PRESET_HOME);
+		let request = climate_command(&entities, &climate, &home, (1, 4)).unwrap();
+		assert!(request.has_legacy_away && !request.legacy_away);
+	}
+
+	#[test]
+	fn legacy_climate_presets() {
+		let mut info = api::ListEntitiesClimateResponse::new();
+		info.supported_presets = vec![
+			api::ClimatePreset::CLIMATE_PRESET_HOME.into(),
+			api::ClimatePreset::CLIMATE_PRESET_ECO.into(),
+		];
+		let (entities, climate) = listed(5, EntityKind::Climate(info.into()));
+		let eco = ClimateCommand::new().preset(api::ClimatePreset::CLIMATE_PRESET_ECO);
+		climate_command(&entities, &climate, &eco, (1, 5)).unwrap();
+		assert_invalid(climate_command(&entities, &climate, &eco, (1, 4)));
+	}
+
+	fn number_with_range(min: f32, max: f32, step: f32) -> (HashMap<u32, EntityType>, Entity) {
+		let mut info = api::ListEntitiesNumberResponse::new();
+		info.min_value = min;
+		info.max_value = max;
+		info.step = step;
+		listed(6, EntityKind::Number(info.into()))
+	}
+
+	#[test]
+	#[allow(clippy::float_cmp)]
+	fn number_snapping() {
+		let (entities, number) = number_with_range(0.0, 10.0, 0.5);
+		let set = |value, clamp| number_command(&entities, &number, value, clamp).map(|r| r.state);
+		assert_eq!(set(3.3, false).unwrap(), 3.5);
+		assert_eq!(set(3.2, false).unwrap(), 3.0);
+		assert_eq!(set(12.0, true).unwrap(), 10.0);
+		assert_eq!(set(-1.0, true).unwrap(), 0.0);
+		assert_invalid(set(12.0, false));
+		assert_invalid(set(f32::NAN, true));
+
+		let (entities, uneven) = number_with_range(0.0, 1.0, 0.3);
+		let request = number_command(&entities, &uneven, 1.0, false).unwrap();
+		assert!((request.state - 0.9).abs() < 1e-6);
+	}
+
+	#[test]
+	fn number_invalid_range() {
+		for (min, max) in [(10.0, 0.0), (f32::NAN, 10.0), (0.0, f32::NAN)] {
+			let (entities, number) = number_with_range(min, max, 1.0);
+			assert_invalid(number_command(&entities, &number, 5.0, true));
+		}
+	}
+
+	#[test]
+	fn select_options() {
+		let mut info = api::ListEntitiesSelectResponse::new();
+		info.options = vec!["Eco".to_string(), "Boost".to_string()];
+		let (entities, select) = listed(7, EntityKind::Select(info.into()));
+		let request = select_command(&entities, &select, "Boost").unwrap();
+		assert_eq!(request.state, "Boost");
+		assert_invalid(select_command(&entities, &select, "boost"));
+	}
+
+	#[test]
+	fn lock_capabilities() {
+		let mut info = api::ListEntitiesLockResponse::new();
+		info.requires_code = true;
+		let (entities, lock) = listed(8, EntityKind::Lock(info.into()));
+		let request = lock_command(
+			&entities,
+			&lock,
+			api::LockCommand::LOCK_UNLOCK,
+			Some("1234"),
+		)
+		.unwrap();
+		assert!(request.has_code);
+		assert_eq!(request.code, "1234");
+		assert_invalid(lock_command(
+			&entities,
+			&lock,
+			api::LockCommand::LOCK_UNLOCK,
+			None,
+		));
+		assert_invalid(lock_command(
+			&entities,
+			&lock,
+			api::LockCommand::LOCK_OPEN,
+			Some("1234"),
+		));
+	}
+
+	#[test]
+	fn media_player_capabilities() {
+		let (entities, player) = listed(
+			9,
+			EntityKind::MediaPlayer(api::ListEntitiesMediaPlayerResponse::new().into()),
+		);
+		let command = MediaPlayerCommand::play().volume(0.5);
+		media_player_command(&entities, &player, &command).unwrap();
+		assert_invalid(media_player_command(
+			&entities,
+			&player,
+			&MediaPlayerCommand::pause(),
+		));
+		assert_invalid(media_player_command(
+			&entities,
+			&player,
+			&MediaPlayerCommand::new().volume(1.5),
+		));
+
+		let mut info = api::ListEntitiesMediaPlayerResponse::new();
+		info.supports_pause = true;
+		let (entities, player) = listed(9, EntityKind::MediaPlayer(info.into()));
+		media_player_command(&entities, &player, &MediaPlayerCommand::pause()).unwrap();
+	}
+
+	fn service() -> (HashMap<u32, EntityType>, Entity) {
+		let mut info = api::ListEntitiesServicesResponse::new();
+		for (name, arg_type) in [
+			("count", api::ServiceArgType::SERVICE_ARG_TYPE_INT),
+			("labels", api::ServiceArgType::SERVICE_ARG_TYPE_STRING_ARRAY),
+		] {
+			let mut argument = api::ListEntitiesServicesArgument::new();
+			argument.name = name.to_string();
+			argument.type_ = arg_type.into();
+			info.args.push(argument);
+		}
+		listed(10, EntityKind::Services(info.into()))
+	}
+
+	#[test]
+	fn service_arguments() {
+		let (entities, service) = service();
+		let arguments = [
+			ServiceValue::Int(-3),
+			ServiceValue::StringArray(vec!["a".to_string()]),
+		];
+		let request = execute_service_command(&entities, &service, &arguments, (1, 3)).unwrap();
+		assert_eq!(request.key, 10);
+		assert_eq!((request.args[0].int_, request.args[0].legacy_int), (-3, 0));
+		assert_eq!(request.args[1].string_array, ["a"]);
+
+		for arguments in [
+			vec![ServiceValue::Int(1)],
+			vec![ServiceValue::Float(1.0), ServiceValue::StringArray(vec![])],
+			vec![ServiceValue::StringArray(vec![]), ServiceValue::Int(1)],
+		] {
+			assert_invalid(execute_service_command(
+				&entities,
+				&service,
+				&arguments,
+				(1, 3),
+			));
+		}
+	}
+
+	#[test]
+	fn legacy_service_int() {
+		let (entities, service) = service();
+		let arguments = [ServiceValue::Int(-3), ServiceValue::StringArray(vec![])];
+		let request = execute_service_command(&entities, &service, &arguments, (1, 2)).unwrap();
+		assert_eq!((request.args[0].int_, request.args[0].legacy_int), (0, -3));
+	}
+}
@@ -1,51 +1,343 @@
 use crate::{
 	api::{self, HelloResponse},
+	frame::{self, Frame, Framing, NoiseHandshake},
+	homeassistant::{HomeAssistantStateProvider, ServiceCall, ServiceCallRouter, StateImports},
 	model::State,
-	Device, Entity, EspHomeError, MessageType,
+	subscription::{StateChange, StateFilter, StateTracker},
+	Device, Entity, EntityType, EspHomeError, Event, MessageType,
 };
 use num_traits::FromPrimitive;
-use protobuf::{CodedInputStream, CodedOutputStream};
 use std::{
-	collections::HashMap,
+	any::Any,
+	collections::VecDeque,
 	error::Error,
 	io::{Read, Write},
-	mem::MaybeUninit,
-	time::{SystemTime, UNIX_EPOCH},
+	net::{TcpStream, ToSocketAddrs},
+	sync::mpsc::{Receiver, Sender},
+	time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
-#[derive(Debug)]
-pub(crate) struct MessageHeader {
-	message_length: u32,
-	message_type: u32,
+/// Result of handling a message that the device may send at any time.
+pub(crate) enum Unsolicited {
+	/// The message is not unsolicited and should be handed to the caller.
+	NotHandled,
+
+	/// The message was handled.
+	Handled,
+
+	/// The message was handled and the given reply should be sent.
+	Reply(MessageType, Vec<u8>),
+
+	/// The device is closing the connection. The given disconnect response should be sent.
+	Disconnect(Vec<u8>),
 }
 
-impl MessageHeader {
-	pub(crate) fn message_type(&self) -> u32 {
-		self.message_type
+/// Handle pings, time requests, state updates, service calls and state imports for both kinds of connection.
+pub(crate) fn handle_unsolicited(
+	states: &mut StateTracker,
+	service_calls: &mut ServiceCallRouter,
+	imports: &mut StateImports,
+	frame: &Frame,
+) -> Result<Unsolicited, EspHomeError> {
+	match FromPrimitive::from_u32(frame.message_type()) {
+		Some(MessageType::PingRequest) => {
+			frame.parse::<api::PingRequest>()?;
+			Ok(Unsolicited::Reply(
+				MessageType::PingResponse,
+				protobuf::Message::write_to_bytes(&api::PingResponse::new())?,
+			))
+		}
+		Some(MessageType::DisconnectRequest) => {
+			frame.parse::<api::DisconnectRequest>()?;
+			Ok(Unsolicited::Disconnect(protobuf::Message::write_to_bytes(
+				&api::DisconnectResponse::new(),
+			)?))
+		}
+		Some(MessageType::GetTimeRequest) => {
+			frame.parse::<api::GetTimeRequest>()?;
+			let mut res = api::GetTimeResponse::new();
+			res.epoch_seconds =
+				u32::try_from(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs())?;
+			Ok(Unsolicited::Reply(
+				MessageType::GetTimeResponse,
+				protobuf::Message::write_to_bytes(&res)?,
+			))
+		}
+
+		Some(MessageType::SensorStateResponse) => {
+			let ssr: api::SensorStateResponse = frame.parse()?;
+			let state = if ssr.missing_state {
+				State::Unknown
+			} else {
+				State::Measurement(ssr.state)
+			};
+			states.update(ssr.key, EntityType::Sensor, state);
+			Ok(Unsolicited::Handled)
+		}
+
+		Some(MessageType::BinarySensorStateResponse) => {
+			let ssr: api::BinarySensorStateResponse = frame.parse()?;
+			let state = if ssr.missing_state {
+				State::Unknown
+			} else {
+				State::Binary(ssr.state)
+			};
+			states.update(ssr.key, EntityType::BinarySensor, state);
+			Ok(Unsolicited::Handled)
+		}
+
+		Some(MessageType::TextSensorStateResponse) => {
+			let ssr: api::TextSensorStateResponse = frame.parse()?;
+			let state = if ssr.missing_state {
+				State::Unknown
+			} else {
+				State::Text(ssr.state)
+			};
+			states.update(ssr.key, EntityType::TextSensor, state);
+			Ok(Unsolicited::Handled)
+		}
+
+		Some(MessageType::SwitchStateResponse) => {
+			let ssr: api::SwitchStateResponse = frame.parse()?;
+			states.update(ssr.key, EntityType::Switch, State::Binary(ssr.state));
+			Ok(Unsolicited::Handled)
+		}
+
+		Some(MessageType::NumberStateResponse) => {
+			let ssr: api::NumberStateResponse = frame.parse()?;
+			let state = if ssr.missing_state {
+				State::Unknown
+			} else {
+				State::Measurement(ssr.state)
+			};
+			states.update(ssr.key, EntityType::Number, state);
+			Ok(Unsolicited::Handled)
+		}
+
+		Some(MessageType::SelectStateResponse) => {
+			let ssr: api::SelectStateResponse = frame.parse()?;
+			let state = if ssr.missing_state {
+				State::Unknown
+			} else {
+				State::Text(ssr.state)
+			};
+			states.update(ssr.key, EntityType::Select, state);
+			Ok(Unsolicited::Handled)
+		}
+
+		Some(MessageType::LockStateResponse) => {
+			let ssr: api::LockStateResponse = frame.parse()?;
+			states.update(
+				ssr.key,
+				EntityType::Lock,
+				State::Lock(ssr.state.enum_value_or_default()),
+			);
+			Ok(Unsolicited::Handled)
+		}
+
+		Some(MessageType::ClimateStateResponse) => {
+			let ssr: api::ClimateStateResponse = frame.parse()?;
+			states.update(ssr.key, EntityType::Climate, State::Climate(ssr.into()));
+			Ok(Unsolicited::Handled)
+		}
+
+		Some(MessageType::CoverStateResponse) => {
+			let ssr: api::CoverStateResponse = frame.parse()?;
+			states.update(ssr.key, EntityType::Cover, State::Cover(ssr.into()));
+			Ok(Unsolicited::Handled)
+		}
+
+		Some(MessageType::FanStateResponse) => {
+			let ssr: api::FanStateResponse = frame.parse()?;
+			states.update(ssr.key, EntityType::Fan, State::Fan(ssr.into()));
+			Ok(Unsolicited::Handled)
+		}
+
+		Some(MessageType::LightStateResponse) => {
+			let ssr: api::LightStateResponse = frame.parse()?;
+			states.update(ssr.key, EntityType::Light, State::Light(ssr.into()));
+			Ok(Unsolicited::Handled)
+		}
+
+		Some(MessageType::MediaPlayerStateResponse) => {
+			let ssr: api::MediaPlayerStateResponse = frame.parse()?;
+			states.update(
+				ssr.key,
+				EntityType::MediaPlayer,
+				State::MediaPlayer(ssr.into()),
+			);
+			Ok(Unsolicited::Handled)
+		}
+
+		Some(MessageType::HomeassistantServiceResponse) => {
+			service_calls.dispatch(&frame.parse()?);
+			Ok(Unsolicited::Handled)
+		}
+
+		Some(MessageType::SubscribeHomeAssistantStateResponse) => {
+			match imports.request(&frame.parse()?) {
+				Some(response) => Ok(Unsolicited::Reply(
+					MessageType::HomeAssistantStateResponse,
+					protobuf::Message::write_to_bytes(&response)?,
+				)),
+				None => Ok(Unsolicited::Handled),
+			}
+		}
+
+		// Messages the device pushes after a subscription, and message types introduced by newer firmware, are only
+		// returned by next_event
+		Some(
+			MessageType::SubscribeLogsResponse
+			| MessageType::CameraImageResponse
+			| MessageType::BluetoothLEAdvertisementResponse,
+		)
+		| None => Ok(Unsolicited::Handled),
+
+		Some(_) => Ok(Unsolicited::NotHandled),
 	}
 }
 
-pub struct Connection<'a> {
-	cis: CodedInputStream<'a>,
-	cos: CodedOutputStream<'a>,
-	states: HashMap<u32, State>,
+/// Remove and return the first kept message for which `select` returns a value, for both kinds of connection.
+pub(crate) fn take_pending_event<T, F>(
+	pending_events: &mut VecDeque<Frame>,
+	select: &mut F,
+) -> Option<T>
+where
+	F: FnMut(&Event) -> Option<T>,
+{
+	let (index, selected) = pending_events
+		.iter()
+		.enumerate()
+		.find_map(|(index, frame)| Some((index, select(&Event::decode(frame).ok()?)?)))?;
+	pending_events.remove(index);
+	Some(selected)
+}
+
+/// Whether an I/O error was caused by a read timeout, rather than by the connection failing.
+fn is_timeout(error: &std::io::Error) -> bool {
+	matches!(
+		error.kind(),
+		std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+	)
+}
+
+/// Default maximum size of an incoming message (see [`Connection::with_max_frame_length`]).
+pub const DEFAULT_MAX_FRAME_LENGTH: usize = 1024 * 1024;
+
+/// Default number of received messages kept for [`Connection::next_event`] (see
+/// [`Connection::with_max_pending_events`]).
+pub const DEFAULT_MAX_PENDING_EVENTS: usize = 256;
+
+/// A bidirectional byte stream that a [`Connection`] can be created on, such as a `TcpStream`.
+pub trait Stream: Read + Write + Send {}
+
+impl<T> Stream for T where T: Read + Write + Send {}
+
+/// Sets (or with `None`, clears) the read timeout of the stream underlying a connection (see
+/// [`Connection::with_read_timeout_hook`]).
+pub type ReadTimeoutHook = Box<dyn Fn(Option<Duration>) -> std::io::Result<()> + Send>;
+
+pub struct Connection {
+	stream: Box<dyn Stream>,
+
+	/// Sets read timeouts on the stream, when it supports them.
+	read_timeout: Option<ReadTimeoutHook>,
+	framing: Framing,
+	read_buffer: Vec<u8>,
+	max_frame_length: usize,
+	states: StateTracker,
+	service_calls: ServiceCallRouter,
+	imports: StateImports,
+	pending_events: VecDeque<Frame>,
+	max_pending_events: usize,
+	connected: bool,
 }
 
-impl<'a> Connection<'a> {
-	pub fn new<R, W>(reader: &'a mut R, writer: &'a mut W) -> Connection<'a>
+impl Connection {
+	pub fn new<S>(stream: S) -> Connection
 	where
-		R: Read,
-		W: Write,
+		S: Stream + 'static,
 	{
+		let read_timeout = (&stream as &dyn Any)
+			.downcast_ref::<TcpStream>()
+			.and_then(|s| s.try_clone().ok())
+			.map(|socket| -> ReadTimeoutHook {
+				Box::new(move |timeout| socket.set_read_timeout(timeout))
+			});
+
 		Connection {
-			cis: CodedInputStream::new(reader),
-			cos: CodedOutputStream::new(writer),
-			states: HashMap::new(),
+			stream: Box::new(stream),
+			read_timeout,
+			framing: Framing::Plaintext,
+			read_buffer: Vec::new(),
+			max_frame_length: DEFAULT_MAX_FRAME_LENGTH,
+			states: StateTracker::default(),
+			service_calls: ServiceCallRouter::default(),
+			imports: StateImports::default(),
+			pending_events: VecDeque::new(),
+			max_pending_events: DEFAULT_MAX_PENDING_EVENTS,
+			connected: true,
 		}
 	}
+
+	/// Create a connection that uses Noise encryption with the given pre-shared key. The key is the base64-encoded
+	/// value configured as `api: encryption: key:` on the device.
+	pub fn new_encrypted<S>(stream: S, key: &str) -> Result<Connection, EspHomeError>
+	where
+		S: Stream + 'static,
+	{
+		let mut connection = Connection::new(stream);
+		connection.framing = Framing::noise(key)?;
+		Ok(connection)
+	}
+
+	/// Set the maximum size of an incoming message. Receiving a larger message fails with
+	/// [`EspHomeError::FrameTooLarge`], after which the connection can no longer be used.
+	#[must_use]
+	pub fn with_max_frame_length(mut self, max_frame_length: usize) -> Connection {
+		self.max_frame_length = max_frame_length;
+		self
+	}
+
+	/// Set the function used to apply read timeouts to the stream. This is done automatically for a `TcpStream`;
+	/// other streams (such as a TLS stream wrapping a socket) need it for the methods that wait for a limited time,
+	/// which otherwise fail with [`EspHomeError::TimeoutUnsupported`]. A read that times out must fail with
+	/// `WouldBlock` or `TimedOut`.
+	#[must_use]
+	pub fn with_read_timeout_hook<F>(mut self, hook: F) -> Connection
+	where
+		F: Fn(Option<Duration>) -> std::io::Result<()> + Send + 'static,
+	{
+		self.read_timeout = Some(Box::new(hook));
+		self
+	}
+
+	/// Set how many received messages are kept for [`Connection::next_event`] while waiting for replies to requests (or
+	/// until the application calls it). When more arrive, the oldest ones are dropped.
+	#[must_use]
+	pub fn with_max_pending_events(mut self, max_pending_events: usize) -> Connection {
+		self.max_pending_events = max_pending_events;
+		self
+	}
+
+	/// Open a TCP connection to the device at the given address (usually port 6053).
+	pub fn open<A>(address: A) -> Result<Connection, EspHomeError>
+	where
+		A: ToSocketAddrs,
+	{
+		Ok(Connection::new(TcpStream::connect(address)?))
+	}
+
+	/// Open a TCP connection to the device at the given address, using Noise encryption with the given pre-shared key.
+	pub fn open_encrypted<A>(address: A, key: &str) -> Result<Connection, EspHomeError>
+	where
+		A: ToSocketAddrs,
+	{
+		Connection::new_encrypted(TcpStream::connect(address)?, key)
+	}
 }
 
-impl<'a> Connection<'a> {
+impl Connection {
 	pub(crate) fn send_message<M>(
 		&mut self,
 		message_type: MessageType,
@@ -54,143 +346,316 @@ impl<'a> Connection<'a> {
 	where
 		M: protobuf::Message,
 	{
-		let message_bytes = message.write_to_bytes()?;
-		self.cos.write_raw_byte(0)?;
-		self.cos.write_raw_varint32(u32::try_from(message_bytes.len())?)?;
-		self.cos.write_raw_varint32(message_type as u32)?;
-		self.cos.write_raw_bytes(&message_bytes)?;
-		self.cos.flush()?;
+		self.send_bytes(message_type, &message.write_to_bytes()?)
+	}
+
+	fn send_bytes(&mut self, message_type: MessageType, body: &[u8]) -> Result<(), EspHomeError> {
+		let bytes = self.framing.encode(message_type as u32, body)?;
+		self.write_raw(&bytes)
+	}
+
+	fn write_raw(&mut self, bytes: &[u8]) -> Result<(), EspHomeError> {
+		let result = self
+			.stream
+			.write_all(bytes)
+			.and_then(|()| self.stream.flush());
+		self.connected &= result.is_ok();
+		Ok(result?)
+	}
+
+	/// Read more bytes from the stream into the read buffer.
+	fn fill(&mut self) -> Result<(), EspHomeError> {
+		let mut chunk = [0u8; 4096];
+		let result = match self.stream.read(&mut chunk) {
+			Ok(0) => Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof)),
+			result => result,
+		};
+		if let Err(e) = &result {
+			self.connected &= is_timeout(e);
+		}
+		let read = result?;
+		self.read_buffer.extend_from_slice(&chunk[0..read]);
+		Ok(())
+	}
+
+	fn read_noise_frame(&mut self) -> Result<Vec<u8>, EspHomeError> {
+		loop {
+			if let Some(frame) =
+				frame::take_noise_frame(&mut self.read_buffer, self.max_frame_length)?
+			{
+				return Ok(frame);
+			}
+			self.fill()?;
+		}
+	}
+
+	/// Perform the Noise handshake. Does nothing for plaintext connections.
+	fn handshake(&mut self) -> Result<(), EspHomeError> {
+		let Some(psk) = self.framing.pending_handshake() else {
+			return Ok(());
+		};
+
+		let (handshake, hello) = NoiseHandshake::start(&psk)?;
+		self.write_raw(&hello)?;
+		NoiseHandshake::server_hello(&self.read_noise_frame()?)?;
+		let transport = handshake.finish(&self.read_noise_frame()?)?;
+		self.framing.complete_handshake(transport);
 		Ok(())
 	}
 
 	pub fn get_last_state(&mut self, entity: &Entity) -> Result<Option<State>, Box<dyn Error>> {
-		match self.states.get(&entity.key()) {
+		match self.states.get(entity.key()) {
 			Some(s) => Ok(Some(s.clone())),
 			None => Ok(None),
 		}
 	}
 
-	pub(crate) fn receive_message<M>(
-		&mut self,
-		message_type: MessageType,
-	) -> Result<M, EspHomeError>
+	/// Receive the state changes selected by `filter` (for instance an `&Entity` or an `EntityType`) on a channel.
+	/// Changes are sent while the connection processes incoming messages, so the connection must be polled (for
+	/// instance with [`Connection::next_event`]) for them to arrive.
+	pub fn state_changes<F>(&mut self, filter: F) -> Receiver<StateChange>
 	where
-		M: protobuf::Message,
+		F: Into<StateFilter>,
 	{
-		let header = self.receive_message_header()?;
-		if header.message_type != (message_type as u32) {
-			return Err(EspHomeError::UnexpectedResponse {
-				expected: message_type,
-				received: header.message_type,
-			});
+		self.states.subscribe(filter.into())
+	}
+
+	/// Call `callback` for each state change selected by `filter`, with the previous and new state.
+	pub fn on_state_change<F, C>(&mut self, filter: F, callback: C)
+	where
+		F: Into<StateFilter>,
+		C: FnMut(&StateChange) + Send + 'static,
+	{
+		self.states.on_change(filter.into(), callback);
+	}
+
+	/// Call `handler` for each Home Assistant service call (or event) with the given name that the device requests.
+	/// The device only sends these after [`AuthenticatedDevice::subscribe_home_assistant_services`](crate::AuthenticatedDevice::subscribe_home_assistant_services).
+	pub fn on_service_call<F>(&mut self, service: &str, handler: F)
+	where
+		F: FnMut(&ServiceCall) + Send + 'static,
+	{
+		self.service_calls.add(service, handler);
+	}
+
+	/// Answer the Home Assistant states that the device imports from `provider`. The device only asks for them after
+	/// [`AuthenticatedDevice::subscribe_home_assistant_states`](crate::AuthenticatedDevice::subscribe_home_assistant_states).
+	///
+	/// Send the ids of entities whose state changes in the provider on the returned sender. Their new values are sent
+	/// to the device the next time the connection reads from it.
+	pub fn set_state_provider<P>(&mut self, provider: P) -> Sender<String>
+	where
+		P: HomeAssistantStateProvider + 'static,
+	{
+		self.imports.set_provider(Box::new(provider))
+	}
+
+	/// Send the device the current values of `entity_id` (and its attributes) from the provider, if it imports them.
+	/// Call this whenever the provider's state of the entity changes, unless the change is reported on the sender
+	/// returned by [`Connection::set_state_provider`].
+	pub fn home_assistant_state_changed(&mut self, entity_id: &str) -> Result<(), EspHomeError> {
+		for response in self.imports.changed(entity_id) {
+			self.send_message(MessageType::HomeAssistantStateResponse, &response)?;
 		}
-		self.receive_message_body(&header)
+		Ok(())
+	}
+
+	pub(crate) fn last_state(&self, key: u32) -> Option<&State> {
+		self.states.get(key)
+	}
+
+	/// Whether the connection to the device is still up. This becomes false when reading from or writing to the
+	/// stream fails, when an invalid or too large frame is received, or when the device closes the connection.
+	#[must_use]
+	pub fn is_connected(&self) -> bool {
+		self.connected
 	}
 
-	pub(crate) fn receive_message_body<M>(
+	/// Whether the entity is available: the connection is up and the device has reported a valid state for it.
+	#[must_use]
+	pub fn is_available(&self, entity: &Entity) -> bool {
+		self.connected
+			&& matches!(self.states.get(entity.key()), Some(state) if *state != State::Unknown)
+	}
+
+	pub(crate) fn receive_message<M>(
 		&mut self,
-		header: &MessageHeader,
+		message_type: MessageType,
 	) -> Result<M, EspHomeError>
 	where
 		M: protobuf::Message,
 	{
-		let mut message_bytes: [MaybeUninit<u8>; 4096] =
-			unsafe { MaybeUninit::uninit().assume_init() };
-		self.cis
-			.read_exact(&mut message_bytes[0..header.message_length as usize])?;
-		let data = unsafe { std::mem::transmute::<_, [u8; 4096]>(message_bytes) };
-		Ok(M::parse_from_bytes(
-			&data[0..header.message_length as usize],
-		)?)
-	}
-
-	fn ignore_bytes(&mut self, bytes: u32) -> Result<(), EspHomeError> {
-		self.cis.skip_raw_bytes(bytes)?;
-		Ok(())
+		loop {
+			let frame = self.receive_frame()?;
+			if frame.message_type() == (message_type as u32) {
+				return frame.parse();
+			}
+
+			// Not the reply we are waiting for; keep it for next_event
+			self.queue_event(frame);
+		}
 	}
 
-	fn process_unsolicited(&mut self, header: &MessageHeader) -> Result<bool, EspHomeError> {
-		match FromPrimitive::from_u32(header.message_type) {
-			Some(MessageType::PingRequest) => {
-				self.receive_message_body::<api::PingRequest>(header)?;
-				self.send_message(MessageType::PingResponse, &api::PingResponse::new())?;
+	fn process_unsolicited(&mut self, frame: &Frame) -> Result<bool, EspHomeError> {
+		match handle_unsolicited(
+			&mut self.states,
+			&mut self.service_calls,
+			&mut self.imports,
+			frame,
+		)? {
+			Unsolicited::NotHandled => Ok(false),
+			Unsolicited::Handled => Ok(true),
+			Unsolicited::Reply(message_type, body) => {
+				self.send_bytes(message_type, &body)?;
 				Ok(true)
 			}
-			Some(MessageType::DisconnectRequest) => {
-				self.receive_message_body::<api::DisconnectRequest>(header)?;
-				self.send_message(
-					MessageType::DisconnectResponse,
-					&api::DisconnectResponse::new(),
-				)?;
-				// TODO: actually disconnect
-				Ok(true)
-			}
-			Some(MessageType::GetTimeRequest) => {
-				self.receive_message_body::<api::GetTimeRequest>(header)?;
-				let mut res = api::GetTimeResponse::new();
-				res.epoch_seconds =
-					u32::try_from(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs())?;
-				self.send_message(MessageType::GetTimeResponse, &res)?;
+			Unsolicited::Disconnect(body) => {
+				self.send_bytes(MessageType::DisconnectResponse, &body)?;
+				self.connected = false;
 				Ok(true)
 			}
+		}
+	}
 
-			Some(MessageType::SensorStateResponse) => {
-				let ssr: api::SensorStateResponse = self.receive_message_body(header)?;
-				self.states.insert(ssr.key, State::Measurement(ssr.state));
-				Ok(true)
-			}
+	/// Read the next frame from the stream.
+	fn read_frame(&mut self) -> Result<Frame, EspHomeError> {
+		for response in self.imports.take_changes() {
+			self.send_message(MessageType::HomeAssistantStateResponse, &response)?;
+		}
 
-			Some(MessageType::BinarySensorStateResponse) => {
-				let ssr: api::BinarySensorStateResponse = self.receive_message_body(header)?;
-				self.states.insert(ssr.key, State::Binary(ssr.state));
-				Ok(true)
-			}
+		loop {
+			let frame = self
+				.framing
+				.take_frame(&mut self.read_buffer, self.max_frame_length);
 
-			Some(MessageType::TextSensorStateResponse) => {
-				let ssr: api::TextSensorStateResponse = self.receive_message_body(header)?;
-				self.states.insert(ssr.key, State::Text(ssr.state));
-				Ok(true)
+			// The rest of the stream cannot be read after an invalid or rejected frame
+			self.connected &= frame.is_ok();
+			if let Some(frame) = frame? {
+				return Ok(frame);
 			}
+			self.fill()?;
+		}
+	}
 
-			// State updates
-			Some(
-				MessageType::CoverStateResponse
-				| MessageType::FanStateResponse
-				| MessageType::LightStateResponse
-				| MessageType::SwitchStateResponse
-				| MessageType::ClimateStateResponse
-				| MessageType::NumberStateResponse
-				| MessageType::SelectStateResponse,
-			) => {
-				// Skip these messages
-				println!("Receive state update: {:?}", header.message_type);
-				self.ignore_bytes(header.message_length)?;
-				Ok(true)
-			}
+	/// Keep a frame that was received while waiting for something else, to be returned by [`Connection::next_event`].
+	pub(crate) fn queue_event(&mut self, frame: Frame) {
+		if self.pending_events.len() >= self.max_pending_events {
+			self.pending_events.pop_front();
+		}
+		if self.max_pending_events > 0 {
+			self.pending_events.push_back(frame);
+		}
+	}
+
+	/// Drop the kept frames for which `discard` returns true, for example because they are out of date.
+	pub(crate) fn discard_pending_events<F>(&mut self, mut discard: F)
+	where
+		F: FnMut(&Event) -> bool,
+	{
+		self.pending_events
+			.retain(|frame| !Event::decode(frame).is_ok_and(|event| discard(&event)));
+	}
+
+	pub(crate) fn receive_frame(&mut self) -> Result<Frame, EspHomeError> {
+		loop {
+			let frame = self.read_frame()?;
 
-			Some(_) => Ok(false),
-			None => {
-				panic!("unknown message type received: {}", header.message_type);
+			// Handle internal messages
+			if !self.process_unsolicited(&frame)? {
+				return Ok(frame);
 			}
+
+			// Keep them for the application, which receives them from next_event
+			self.queue_event(frame);
 		}
 	}
 
-	pub(crate) fn receive_message_header(&mut self) -> Result<MessageHeader, EspHomeError> {
+	/// Receive the next message from the device. Pings and time requests are answered and states are recorded (see
+	/// [`Connection::get_last_state`]) before the message is returned.
+	///
+	/// Messages that arrive while waiting for the reply to a request are kept and returned by subsequent calls (up to
+	/// a limit, see [`Connection::with_max_pending_events`]).
+	pub fn next_event(&mut self) -> Result<Event, EspHomeError> {
+		if let Some(frame) = self.pending_events.pop_front() {
+			return Event::decode(&frame);
+		}
+
+		let frame = self.read_frame()?;
+		self.process_unsolicited(&frame)?;
+		Event::decode(&frame)
+	}
+
+	/// Receive the next message for which `select` returns a value, and return that value. Other messages are kept
+	/// for [`Connection::next_event`], as if they arrived while waiting for a reply.
+	pub(crate) fn next_event_matching<T, F>(&mut self, mut select: F) -> Result<T, EspHomeError>
+	where
+		F: FnMut(&Event) -> Option<T>,
+	{
+		if let Some(selected) = take_pending_event(&mut self.pending_events, &mut select) {
+			return Ok(selected);
+		}
 		loop {
-			let mut zero = [MaybeUninit::uninit(); 1];
-			self.cis.read_exact(&mut zero)?;
-			let len = self.cis.read_raw_varint32()?;
-			let tp = self.cis.read_raw_varint32()?;
-
-			let header = MessageHeader {
-				message_length: len,
-				message_type: tp,
-			};
+			let frame = self.read_frame()?;
+			self.process_unsolicited(&frame)?;
+			if let Some(selected) = select(&Event::decode(&frame)?) {
+				return Ok(selected);
+			}
+			self.queue_event(frame);
+		}
+	}
 
-			// Handle internal messages
-			if !self.process_unsolicited(&header)? {
-				return Ok(header);
+	/// Like [`Connection::next_event_matching`], but returns `None` when no selected message arrived within (roughly)
+	/// `timeout`. Fails with [`EspHomeError::TimeoutUnsupported`] when no read timeout can be set on the stream.
+	pub(crate) fn next_event_matching_timeout<T, F>(
+		&mut self,
+		timeout: Duration,
+		mut select: F,
+	) -> Result<Option<T>, EspHomeError>
+	where
+		F: FnMut(&Event) -> Option<T>,
+	{
+		if let Some(selected) = take_pending_event(&mut self.pending_events, &mut select) {
+			return Ok(Some(selected));
+		}
+
+		let deadline = Instant::now() + timeout;
+		while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+			let Some(frame) = self.read_frame_timeout(remaining)? else {
+				break;
+			};
+			self.process_unsolicited(&frame)?;
+			if let Some(selected) = select(&Event::decode(&frame)?) {
+				return Ok(Some(selected));
 			}
+			self.queue_event(frame);
+		}
+		Ok(None)
+	}
+
+	/// Like [`Connection::read_frame`], but returns `None` when no frame arrived within (roughly) `timeout`.
+	fn read_frame_timeout(&mut self, timeout: Duration) -> Result<Option<Frame>, EspHomeError> {
+		let set_read_timeout = self
+			.read_timeout
+			.as_ref()
+			.ok_or(EspHomeError::TimeoutUnsupported)?;
+		set_read_timeout(Some(timeout.max(Duration::from_millis(1))))?;
+		let frame = self.read_frame();
+		if let Some(set_read_timeout) = &self.read_timeout {
+			set_read_timeout(None)?;
+		}
+
+		match frame {
+			Err(EspHomeError::Io(e)) if is_timeout(&e) => Ok(None),
+			frame => frame.map(Some),
+		}
+	}
+
+	/// Iterate over the messages received from the device (see [`Connection::next_event`]). The iterator ends after
+	/// the first error.
+	pub fn events(&mut self) -> Events<'_> {
+		Events {
+			connection: self,
+			failed: false,
 		}
 	}
 
@@ -208,7 +673,9 @@ impl<'a> Connection<'a> {
 		self.receive_message::<R>(reply_type)
 	}
 
-	pub fn connect(mut self) -> Result<Device<'a>, EspHomeError> {
+	pub fn connect(mut self) -> Result<Device, EspHomeError> {
+		self.handshake()?;
+
 		let mut hr = api::HelloRequest::new();
 		hr.client_info = "esphome.rs".to_string();
 		self.send_message(MessageType::HelloRequest, &hr)?;
@@ -217,3 +684,22 @@ impl<'a> Connection<'a> {
 		Ok(Device::new(self, hr))
 	}
 }
+
+/// Iterator over the messages received from the device, returned by [`Connection::events`].
+pub struct Events<'a> {
+	connection: &'a mut Connection,
+	failed: bool,
+}
+
+impl Iterator for Events<'_> {
+	type Item = Result<Event, EspHomeError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.failed {
+			return None;
+		}
+		let event = self.connection.next_event();
+		self.failed = event.is_err();
+		Some(event)
+	}
+}
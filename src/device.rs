@@ -1,19 +1,32 @@
-use crate::connection::Connection;
-use crate::model::{Entity, EntityInfo, EntityKind, ExtendedInfo};
+use crate::camera::{self, ImageAssembler};
+use crate::command::{
+	self, ClimateCommand, CoverCommand, FanCommand, LightCommand, MediaPlayerCommand, ServiceValue,
+};
+use crate::connection::{Connection, Events};
+use crate::model::{
+	BinarySensorInfo, ButtonInfo, CameraInfo, ClimateInfo, CoverInfo, Entity, EntityInfo,
+	EntityKind, EntityType, FanInfo, LightInfo, LockInfo, MediaPlayerInfo, NumberInfo, SelectInfo,
+	SensorInfo, ServiceInfo, SwitchInfo, TextSensorInfo,
+};
 use crate::{
 	api::{self, ConnectResponse, HelloResponse},
-	EspHomeError, MessageType,
+	frame::Frame,
+	BluetoothAdvertisement, EspHomeError, Event, LogRecord, MessageType, ServiceCall,
 };
 use num_traits::FromPrimitive;
-use std::error::Error;
+use std::{
+	collections::HashMap,
+	error::Error,
+	time::{Duration, Instant},
+};
 
-pub struct Device<'a> {
-	pub connection: Connection<'a>,
+pub struct Device {
+	pub connection: Connection,
 	hello_information: api::HelloResponse,
 }
 
-impl<'a> Device<'a> {
-	pub(crate) fn new(connection: Connection<'a>, hello_information: HelloResponse) -> Device<'a> {
+impl Device {
+	pub(crate) fn new(connection: Connection, hello_information: HelloResponse) -> Device {
 		Device {
 			connection,
 			hello_information,
@@ -24,10 +37,16 @@ impl<'a> Device<'a> {
 		self.hello_information.server_info.clone()
 	}
 
-	pub fn authenticate(
-		mut self,
-		password: &str,
-	) -> Result<AuthenticatedDevice<'a>, Box<dyn Error>> {
+	/// Major and minor version of the API implemented by the device.
+	#[must_use]
+	pub fn api_version(&self) -> (u32, u32) {
+		(
+			self.hello_information.api_version_major,
+			self.hello_information.api_version_minor,
+		)
+	}
+
+	pub fn authenticate(mut self, password: &str) -> Result<AuthenticatedDevice, Box<dyn Error>> {
 		let mut cr = api::ConnectRequest::new();
 		cr.password = password.to_string();
 		self.connection
@@ -91,19 +110,15 @@ impl DeviceInfo {
 	pub fn model(&self) -> &str {
 		&self.info.model
 	}
+
+	/// Version of the Bluetooth proxy features of the device, or zero when it is not a Bluetooth proxy.
+	pub fn bluetooth_proxy_version(&self) -> u32 {
+		self.info.bluetooth_proxy_version
+	}
 }
 
-macro_rules! extended_info_from {
+macro_rules! entity_info_from {
 	($message_type: ty) => {
-		impl From<$message_type> for ExtendedInfo {
-			fn from(m: $message_type) -> Self {
-				ExtendedInfo {
-					object_id: m.object_id,
-					unique_id: m.unique_id,
-				}
-			}
-		}
-
 		impl From<$message_type> for EntityInfo {
 			fn from(m: $message_type) -> Self {
 				EntityInfo {
@@ -115,17 +130,20 @@ macro_rules! extended_info_from {
 	};
 }
 
-extended_info_from!(api::ListEntitiesSensorResponse);
-extended_info_from!(api::ListEntitiesBinarySensorResponse);
-extended_info_from!(api::ListEntitiesCoverResponse);
-extended_info_from!(api::ListEntitiesFanResponse);
-extended_info_from!(api::ListEntitiesLightResponse);
-extended_info_from!(api::ListEntitiesSwitchResponse);
-extended_info_from!(api::ListEntitiesTextSensorResponse);
-extended_info_from!(api::ListEntitiesCameraResponse);
-extended_info_from!(api::ListEntitiesClimateResponse);
-extended_info_from!(api::ListEntitiesSelectResponse);
-extended_info_from!(api::ListEntitiesNumberResponse);
+entity_info_from!(api::ListEntitiesSensorResponse);
+entity_info_from!(api::ListEntitiesBinarySensorResponse);
+entity_info_from!(api::ListEntitiesCoverResponse);
+entity_info_from!(api::ListEntitiesFanResponse);
+entity_info_from!(api::ListEntitiesLightResponse);
+entity_info_from!(api::ListEntitiesSwitchResponse);
+entity_info_from!(api::ListEntitiesTextSensorResponse);
+entity_info_from!(api::ListEntitiesCameraResponse);
+entity_info_from!(api::ListEntitiesClimateResponse);
+entity_info_from!(api::ListEntitiesSelectResponse);
+entity_info_from!(api::ListEntitiesNumberResponse);
+entity_info_from!(api::ListEntitiesLockResponse);
+entity_info_from!(api::ListEntitiesButtonResponse);
+entity_info_from!(api::ListEntitiesMediaPlayerResponse);
 
 impl From<api::ListEntitiesServicesResponse> for EntityInfo {
 	fn from(m: api::ListEntitiesServicesResponse) -> Self {
@@ -136,13 +154,158 @@ impl From<api::ListEntitiesServicesResponse> for EntityInfo {
 	}
 }
 
-pub struct AuthenticatedDevice<'a> {
-	pub device: Device<'a>,
+/// A message received in response to a `ListEntitiesRequest`.
+#[allow(clippy::large_enum_variant)]
+pub(crate) enum Listing {
+	Entity(Entity),
+	Done,
 }
 
-impl<'a> AuthenticatedDevice<'a> {
-	fn new(device: Device<'a>) -> AuthenticatedDevice<'a> {
-		AuthenticatedDevice { device }
+/// Decode a message received while listing entities. Returns `None` for messages that are not part of the listing.
+pub(crate) fn decode_listing(frame: &Frame) -> Result<Option<Listing>, EspHomeError> {
+	match FromPrimitive::from_u32(frame.message_type()) {
+		Some(MessageType::ListEntitiesSensorResponse) => {
+			let sr: api::ListEntitiesSensorResponse = frame.parse()?;
+			Ok(Some(Listing::Entity(Entity::new(
+				EntityInfo::from(sr.clone()),
+				EntityKind::Sensor(SensorInfo::from(sr)),
+			))))
+		}
+
+		Some(MessageType::ListEntitiesBinarySensorResponse) => {
+			let sr: api::ListEntitiesBinarySensorResponse = frame.parse()?;
+			Ok(Some(Listing::Entity(Entity::new(
+				EntityInfo::from(sr.clone()),
+				EntityKind::BinarySensor(BinarySensorInfo::from(sr)),
+			))))
+		}
+
+		Some(MessageType::ListEntitiesCoverResponse) => {
+			let sr: api::ListEntitiesCoverResponse = frame.parse()?;
+			Ok(Some(Listing::Entity(Entity::new(
+				EntityInfo::from(sr.clone()),
+				EntityKind::Cover(CoverInfo::from(sr)),
+			))))
+		}
+
+		Some(MessageType::ListEntitiesFanResponse) => {
+			let sr: api::ListEntitiesFanResponse = frame.parse()?;
+			Ok(Some(Listing::Entity(Entity::new(
+				EntityInfo::from(sr.clone()),
+				EntityKind::Fan(FanInfo::from(sr)),
+			))))
+		}
+
+		Some(MessageType::ListEntitiesLightResponse) => {
+			let sr: api::ListEntitiesLightResponse = frame.parse()?;
+			Ok(Some(Listing::Entity(Entity::new(
+				EntityInfo::from(sr.clone()),
+				EntityKind::Light(LightInfo::from(sr)),
+			))))
+		}
+
+		Some(MessageType::ListEntitiesSwitchResponse) => {
+			let sr: api::ListEntitiesSwitchResponse = frame.parse()?;
+			Ok(Some(Listing::Entity(Entity::new(
+				EntityInfo::from(sr.clone()),
+				EntityKind::Switch(SwitchInfo::from(sr)),
+			))))
+		}
+
+		Some(MessageType::ListEntitiesTextSensorResponse) => {
+			let sr: api::ListEntitiesTextSensorResponse = frame.parse()?;
+			Ok(Some(Listing::Entity(Entity::new(
+				EntityInfo::from(sr.clone()),
+				EntityKind::TextSensor(TextSensorInfo::from(sr)),
+			))))
+		}
+
+		Some(MessageType::ListEntitiesCameraResponse) => {
+			let sr: api::ListEntitiesCameraResponse = frame.parse()?;
+			Ok(Some(Listing::Entity(Entity::new(
+				EntityInfo::from(sr.clone()),
+				EntityKind::Camera(CameraInfo::from(sr)),
+			))))
+		}
+
+		Some(MessageType::ListEntitiesClimateResponse) => {
+			let sr: api::ListEntitiesClimateResponse = frame.parse()?;
+			Ok(Some(Listing::Entity(Entity::new(
+				EntityInfo::from(sr.clone()),
+				EntityKind::Climate(ClimateInfo::from(sr)),
+			))))
+		}
+
+		Some(MessageType::ListEntitiesServicesResponse) => {
+			let sr: api::ListEntitiesServicesResponse = frame.parse()?;
+			Ok(Some(Listing::Entity(Entity::new(
+				EntityInfo::from(sr.clone()),
+				EntityKind::Services(ServiceInfo::from(sr)),
+			))))
+		}
+
+		Some(MessageType::ListEntitiesSelectResponse) => {
+			let sr: api::ListEntitiesSelectResponse = frame.parse()?;
+			Ok(Some(Listing::Entity(Entity::new(
+				EntityInfo::from(sr.clone()),
+				EntityKind::Select(SelectInfo::from(sr)),
+			))))
+		}
+
+		Some(MessageType::ListEntitiesNumberResponse) => {
+			let sr: api::ListEntitiesNumberResponse = frame.parse()?;
+			Ok(Some(Listing::Entity(Entity::new(
+				EntityInfo::from(sr.clone()),
+				EntityKind::Number(NumberInfo::from(sr)),
+			))))
+		}
+
+		Some(MessageType::ListEntitiesLockResponse) => {
+			let sr: api::ListEntitiesLockResponse = frame.parse()?;
+			Ok(Some(Listing::Entity(Entity::new(
+				EntityInfo::from(sr.clone()),
+				EntityKind::Lock(LockInfo::from(sr)),
+			))))
+		}
+
+		Some(MessageType::ListEntitiesButtonResponse) => {
+			let sr: api::ListEntitiesButtonResponse = frame.parse()?;
+			Ok(Some(Listing::Entity(Entity::new(
+				EntityInfo::from(sr.clone()),
+				EntityKind::Button(ButtonInfo::from(sr)),
+			))))
+		}
+
+		Some(MessageType::ListEntitiesMediaPlayerResponse) => {
+			let sr: api::ListEntitiesMediaPlayerResponse = frame.parse()?;
+			Ok(Some(Listing::Entity(Entity::new(
+				EntityInfo::from(sr.clone()),
+				EntityKind::MediaPlayer(MediaPlayerInfo::from(sr)),
+			))))
+		}
+
+		Some(MessageType::ListEntitiesDoneResponse) => {
+			frame.parse::<api::ListEntitiesDoneResponse>()?;
+			Ok(Some(Listing::Done))
+		}
+
+		Some(_) | None => Ok(None),
+	}
+}
+
+pub struct AuthenticatedDevice {
+	pub device: Device,
+
+	/// Types of the entities listed by the device, by key.
+	entities: HashMap<u32, EntityType>,
+}
+
+impl AuthenticatedDevice {
+	fn new(device: Device) -> AuthenticatedDevice {
+		AuthenticatedDevice {
+			device,
+			entities: HashMap::new(),
+		}
 	}
 
 	pub fn get_time(&mut self) -> Result<u32, Box<dyn Error>> {
@@ -163,9 +326,17 @@ impl<'a> AuthenticatedDevice<'a> {
 		Ok(DeviceInfo::new(r))
 	}
 
-	pub fn listen(&mut self) -> Result<(), EspHomeError> {
-		let _hdr = self.device.connection.receive_message_header()?;
-		Ok(())
+	/// Wait for the next message from the device (see [`Connection::next_event`]).
+	///
+	/// Methods that wait for particular messages, such as [`AuthenticatedDevice::next_log`], take those messages
+	/// before they are returned here. Everything else they receive is kept and returned by this method.
+	pub fn listen(&mut self) -> Result<Event, EspHomeError> {
+		self.device.connection.next_event()
+	}
+
+	/// Iterate over the messages received from the device (see [`Connection::events`]).
+	pub fn events(&mut self) -> Events<'_> {
+		self.device.connection.events()
 	}
 
 	pub fn subscribe_states(&mut self) -> Result<(), EspHomeError> {
@@ -175,6 +346,84 @@ impl<'a> AuthenticatedDevice<'a> {
 		)
 	}
 
+	/// Ask the device to send log messages of the given level and more severe ones. With `dump_config`, the device
+	/// first logs its configuration. Log messages are returned by [`AuthenticatedDevice::next_log`] (or as
+	/// [`Event::SubscribeLogsResponse`]).
+	pub fn subscribe_logs(
+		&mut self,
+		level: api::LogLevel,
+		dump_config: bool,
+	) -> Result<(), EspHomeError> {
+		let mut request = api::SubscribeLogsRequest::new();
+		request.level = level.into();
+		request.dump_config = dump_config;
+		self.device
+			.connection
+			.send_message(MessageType::SubscribeLogsRequest, &request)
+	}
+
+	/// Wait for the next log message.
+	pub fn next_log(&mut self) -> Result<LogRecord, EspHomeError> {
+		self.device
+			.connection
+			.next_event_matching(|event| match event {
+				Event::SubscribeLogsResponse(response) => Some(LogRecord::from(response)),
+				_ => None,
+			})
+	}
+
+	/// Ask the device to send the Home Assistant service calls and events it makes. They are passed to the handlers
+	/// registered with [`Connection::on_service_call`], and returned as [`Event::HomeassistantServiceResponse`].
+	pub fn subscribe_home_assistant_services(&mut self) -> Result<(), EspHomeError> {
+		self.device.connection.send_message(
+			MessageType::SubscribeHomeassistantServicesRequest,
+			&api::SubscribeHomeassistantServicesRequest::new(),
+		)
+	}
+
+	/// Ask the device which Home Assistant states it imports. Requests are answered from the provider set with
+	/// [`Connection::set_state_provider`] as they are received.
+	pub fn subscribe_home_assistant_states(&mut self) -> Result<(), EspHomeError> {
+		self.device.connection.send_message(
+			MessageType::SubscribeHomeAssistantStatesRequest,
+			&api::SubscribeHomeAssistantStatesRequest::new(),
+		)
+	}
+
+	/// Wait for the next Home Assistant service call or event.
+	pub fn next_service_call(&mut self) -> Result<ServiceCall, EspHomeError> {
+		self.device
+			.connection
+			.next_event_matching(|event| match event {
+				Event::HomeassistantServiceResponse(response) => Some(ServiceCall::from(response)),
+				_ => None,
+			})
+	}
+
+	/// Ask the device to send the Bluetooth LE advertisements it receives. Fails with
+	/// [`EspHomeError::NoBluetoothProxy`] when the device does not report a Bluetooth proxy version.
+	pub fn subscribe_bluetooth_advertisements(&mut self) -> Result<(), EspHomeError> {
+		if self.device_info()?.bluetooth_proxy_version() == 0 {
+			return Err(EspHomeError::NoBluetoothProxy);
+		}
+		self.device.connection.send_message(
+			MessageType::SubscribeBluetoothLEAdvertisementsRequest,
+			&api::SubscribeBluetoothLEAdvertisementsRequest::new(),
+		)
+	}
+
+	/// Wait for the next Bluetooth LE advertisement.
+	pub fn next_bluetooth_advertisement(&mut self) -> Result<BluetoothAdvertisement, EspHomeError> {
+		self.device
+			.connection
+			.next_event_matching(|event| match event {
+				Event::BluetoothLEAdvertisementResponse(response) => {
+					Some(BluetoothAdvertisement::from(response))
+				}
+				_ => None,
+			})
+	}
+
 	pub fn list_entities(&mut self) -> Result<Vec<Entity>, EspHomeError> {
 		self.device.connection.send_message(
 			MessageType::ListEntitiesRequest,
@@ -184,129 +433,285 @@ impl<'a> AuthenticatedDevice<'a> {
 		let mut entities: Vec<Entity> = vec![];
 
 		loop {
-			let header = self.device.connection.receive_message_header()?;
-
-			match FromPrimitive::from_u32(header.message_type()) {
-				Some(MessageType::ListEntitiesSensorResponse) => {
-					let sr: api::ListEntitiesSensorResponse =
-						self.device.connection.receive_message_body(&header)?;
-					entities.push(Entity::new(
-						EntityInfo::from(sr.clone()),
-						EntityKind::Sensor(ExtendedInfo::from(sr)),
-					));
-				}
+			let frame = self.device.connection.receive_frame()?;
 
-				Some(MessageType::ListEntitiesBinarySensorResponse) => {
-					let sr: api::ListEntitiesBinarySensorResponse =
-						self.device.connection.receive_message_body(&header)?;
-					entities.push(Entity::new(
-						EntityInfo::from(sr.clone()),
-						EntityKind::BinarySensor(ExtendedInfo::from(sr)),
-					));
+			match decode_listing(&frame)? {
+				Some(Listing::Entity(entity)) => {
+					self.entities.insert(entity.key(), entity.entity_type());
+					entities.push(entity);
 				}
+				Some(Listing::Done) => break,
 
-				Some(MessageType::ListEntitiesCoverResponse) => {
-					let sr: api::ListEntitiesCoverResponse =
-						self.device.connection.receive_message_body(&header)?;
-					entities.push(Entity::new(
-						EntityInfo::from(sr.clone()),
-						EntityKind::Cover(ExtendedInfo::from(sr)),
-					));
-				}
+				// Anything else that arrives while listing is kept for next_event
+				None => self.device.connection.queue_event(frame),
+			}
+		}
 
-				Some(MessageType::ListEntitiesFanResponse) => {
-					let sr: api::ListEntitiesFanResponse =
-						self.device.connection.receive_message_body(&header)?;
-					entities.push(Entity::new(
-						EntityInfo::from(sr.clone()),
-						EntityKind::Fan(ExtendedInfo::from(sr)),
-					));
-				}
+		Ok(entities)
+	}
 
-				Some(MessageType::ListEntitiesLightResponse) => {
-					let sr: api::ListEntitiesLightResponse =
-						self.device.connection.receive_message_body(&header)?;
-					entities.push(Entity::new(
-						EntityInfo::from(sr.clone()),
-						EntityKind::Light(ExtendedInfo::from(sr)),
-					));
-				}
+	pub fn switch_on(&mut self, entity: &Entity) -> Result<(), EspHomeError> {
+		self.set_switch(entity, true)
+	}
 
-				Some(MessageType::ListEntitiesSwitchResponse) => {
-					let sr: api::ListEntitiesSwitchResponse =
-						self.device.connection.receive_message_body(&header)?;
-					entities.push(Entity::new(
-						EntityInfo::from(sr.clone()),
-						EntityKind::Switch(ExtendedInfo::from(sr)),
-					));
-				}
+	pub fn switch_off(&mut self, entity: &Entity) -> Result<(), EspHomeError> {
+		self.set_switch(entity, false)
+	}
 
-				Some(MessageType::ListEntitiesTextSensorResponse) => {
-					let sr: api::ListEntitiesTextSensorResponse =
-						self.device.connection.receive_message_body(&header)?;
-					entities.push(Entity::new(
-						EntityInfo::from(sr.clone()),
-						EntityKind::TextSensor(ExtendedInfo::from(sr)),
-					));
-				}
+	/// Turn the switch on if its last known state is off, and vice versa. Fails with [`EspHomeError::UnknownState`]
+	/// when no state has been received for the switch (see [`AuthenticatedDevice::subscribe_states`]).
+	pub fn toggle_switch(&mut self, entity: &Entity) -> Result<(), EspHomeError> {
+		command::check_entity(&self.entities, entity, EntityType::Switch)?;
+		let state = command::toggled(entity, self.device.connection.last_state(entity.key()))?;
+		self.set_switch(entity, state)
+	}
 
-				Some(MessageType::ListEntitiesCameraResponse) => {
-					let sr: api::ListEntitiesCameraResponse =
-						self.device.connection.receive_message_body(&header)?;
-					entities.push(Entity::new(
-						EntityInfo::from(sr.clone()),
-						EntityKind::Camera(ExtendedInfo::from(sr)),
-					));
-				}
+	/// Send a command to a light. Fails with [`EspHomeError::InvalidCommand`] when the light does not support it.
+	pub fn light_command(
+		&mut self,
+		entity: &Entity,
+		command: &LightCommand,
+	) -> Result<(), EspHomeError> {
+		let request = command::light_command(&self.entities, entity, command)?;
+		self.device
+			.connection
+			.send_message(MessageType::LightCommandRequest, &request)
+	}
 
-				Some(MessageType::ListEntitiesClimateResponse) => {
-					let sr: api::ListEntitiesClimateResponse =
-						self.device.connection.receive_message_body(&header)?;
-					entities.push(Entity::new(
-						EntityInfo::from(sr.clone()),
-						EntityKind::Climate(ExtendedInfo::from(sr)),
-					));
-				}
+	/// Send a command to a cover. Fails with [`EspHomeError::InvalidCommand`] when the cover does not support it.
+	///
+	/// States of this cover that were kept for [`AuthenticatedDevice::listen`] are dropped, as the command makes them
+	/// out of date.
+	pub fn cover_command(
+		&mut self,
+		entity: &Entity,
+		command: &CoverCommand,
+	) -> Result<(), EspHomeError> {
+		let request =
+			command::cover_command(&self.entities, entity, command, self.device.api_version())?;
+		self.device
+			.connection
+			.discard_pending_events(|event| command::is_cover_state(entity, event));
+		self.device
+			.connection
+			.send_message(MessageType::CoverCommandRequest, &request)
+	}
 
-				Some(MessageType::ListEntitiesServicesResponse) => {
-					let sr: api::ListEntitiesServicesResponse =
-						self.device.connection.receive_message_body(&header)?;
-					entities.push(Entity::new(
-						EntityInfo::from(sr.clone()),
-						EntityKind::Services,
-					));
-				}
+	/// Send a command to a fan. Fails with [`EspHomeError::InvalidCommand`] when the fan does not support it.
+	pub fn fan_command(
+		&mut self,
+		entity: &Entity,
+		command: &FanCommand,
+	) -> Result<(), EspHomeError> {
+		let request =
+			command::fan_command(&self.entities, entity, command, self.device.api_version())?;
+		self.device
+			.connection
+			.send_message(MessageType::FanCommandRequest, &request)
+	}
 
-				Some(MessageType::ListEntitiesSelectResponse) => {
-					let sr: api::ListEntitiesSelectResponse =
-						self.device.connection.receive_message_body(&header)?;
-					entities.push(Entity::new(
-						EntityInfo::from(sr.clone()),
-						EntityKind::Select(ExtendedInfo::from(sr)),
-					));
-				}
+	/// Send a command to a climate device. Fails with [`EspHomeError::InvalidCommand`] when the device does not
+	/// support it.
+	pub fn climate_command(
+		&mut self,
+		entity: &Entity,
+		command: &ClimateCommand,
+	) -> Result<(), EspHomeError> {
+		let request =
+			command::climate_command(&self.entities, entity, command, self.device.api_version())?;
+		self.device
+			.connection
+			.send_message(MessageType::ClimateCommandRequest, &request)
+	}
 
-				Some(MessageType::ListEntitiesNumberResponse) => {
-					let sr: api::ListEntitiesNumberResponse =
-						self.device.connection.receive_message_body(&header)?;
-					entities.push(Entity::new(
-						EntityInfo::from(sr.clone()),
-						EntityKind::Number(ExtendedInfo::from(sr)),
-					));
-				}
+	/// Set a number. The value is rounded to the step of the number, and the value that was sent is returned. Values
+	/// outside of its range are rejected (see [`AuthenticatedDevice::set_number_clamped`]).
+	pub fn set_number(&mut self, entity: &Entity, value: f32) -> Result<f32, EspHomeError> {
+		let request = command::number_command(&self.entities, entity, value, false)?;
+		self.device
+			.connection
+			.send_message(MessageType::NumberCommandRequest, &request)?;
+		Ok(request.state)
+	}
 
-				Some(MessageType::ListEntitiesDoneResponse) => {
-					self.device
-						.connection
-						.receive_message_body::<api::ListEntitiesDoneResponse>(&header)?;
-					break;
-				}
-				Some(_) | None => {
-					panic!("unexpected reply: {:?}", header)
-				}
+	/// Set a number, clamping the value to its range. The value that was sent is returned.
+	pub fn set_number_clamped(&mut self, entity: &Entity, value: f32) -> Result<f32, EspHomeError> {
+		let request = command::number_command(&self.entities, entity, value, true)?;
+		self.device
+			.connection
+			.send_message(MessageType::NumberCommandRequest, &request)?;
+		Ok(request.state)
+	}
+
+	/// Select one of the options of a select. Fails with [`EspHomeError::InvalidCommand`] (listing the valid options)
+	/// when the select does not have the option.
+	pub fn select_option(&mut self, entity: &Entity, option: &str) -> Result<(), EspHomeError> {
+		let request = command::select_command(&self.entities, entity, option)?;
+		self.device
+			.connection
+			.send_message(MessageType::SelectCommandRequest, &request)
+	}
+
+	pub fn press_button(&mut self, entity: &Entity) -> Result<(), EspHomeError> {
+		let request = command::button_command(&self.entities, entity)?;
+		self.device
+			.connection
+			.send_message(MessageType::ButtonCommandRequest, &request)
+	}
+
+	/// Lock, unlock or open a lock. Fails with [`EspHomeError::InvalidCommand`] when the lock requires a code and none
+	/// is given, or when opening is not supported.
+	pub fn lock_command(
+		&mut self,
+		entity: &Entity,
+		command: api::LockCommand,
+		code: Option<&str>,
+	) -> Result<(), EspHomeError> {
+		let request = command::lock_command(&self.entities, entity, command, code)?;
+		self.device
+			.connection
+			.send_message(MessageType::LockCommandRequest, &request)
+	}
+
+	/// Send a command to a media player. Fails with [`EspHomeError::InvalidCommand`] when the media player does not
+	/// support it.
+	pub fn media_player_command(
+		&mut self,
+		entity: &Entity,
+		command: &MediaPlayerCommand,
+	) -> Result<(), EspHomeError> {
+		let request = command::media_player_command(&self.entities, entity, command)?;
+		self.device
+			.connection
+			.send_message(MessageType::MediaPlayerCommandRequest, &request)
+	}
+
+	/// Execute a user-defined service with the given arguments, which must match the arguments the service was listed
+	/// with (see [`ServiceInfo::arguments`]).
+	pub fn execute_service(
+		&mut self,
+		entity: &Entity,
+		arguments: &[ServiceValue],
+	) -> Result<(), EspHomeError> {
+		let request = command::execute_service_command(
+			&self.entities,
+			entity,
+			arguments,
+			self.device.api_version(),
+		)?;
+		self.device
+			.connection
+			.send_message(MessageType::ExecuteServiceRequest, &request)
+	}
+
+	/// Wait for a state update reporting that the cover has stopped moving. Fails with [`EspHomeError::Timeout`]
+	/// when none arrives within `timeout`.
+	///
+	/// The connection must be able to set read timeouts on its stream (see [`Connection::with_read_timeout_hook`]),
+	/// otherwise this fails with [`EspHomeError::TimeoutUnsupported`].
+	pub fn wait_for_cover_idle(
+		&mut self,
+		entity: &Entity,
+		timeout: Duration,
+	) -> Result<(), EspHomeError> {
+		command::check_entity(&self.entities, entity, EntityType::Cover)?;
+		self.device
+			.connection
+			.next_event_matching_timeout(timeout, |event| {
+				command::is_cover_idle(entity, event).then_some(())
+			})?
+			.ok_or(EspHomeError::Timeout)
+	}
+
+	/// Request a single image from the camera and wait until all of it has arrived. Returns the JPEG data.
+	pub fn snapshot(&mut self, camera: &Entity) -> Result<Vec<u8>, EspHomeError> {
+		command::check_entity(&self.entities, camera, EntityType::Camera)?;
+		self.device.connection.send_message(
+			MessageType::CameraImageRequest,
+			&camera::image_request(false),
+		)?;
+		let mut image = ImageAssembler::new(camera.key());
+		loop {
+			let chunk = self
+				.device
+				.connection
+				.next_event_matching(|event| image.accepts(event).then(|| image.push(event)))?;
+			if let Some(jpeg) = chunk {
+				return Ok(jpeg);
 			}
 		}
+	}
 
-		Ok(entities)
+	/// Stream images from the camera. The iterator returns the JPEG data of each image, and keeps requesting images
+	/// for as long as it is used. It ends after the first error.
+	///
+	/// Requests are repeated while waiting for images, which requires read timeouts on the stream of the connection
+	/// (see [`Connection::with_read_timeout_hook`]). Without them, the iterator fails with
+	/// [`EspHomeError::TimeoutUnsupported`].
+	pub fn stream(&mut self, camera: &Entity) -> Result<CameraStream<'_>, EspHomeError> {
+		command::check_entity(&self.entities, camera, EntityType::Camera)?;
+		self.device.connection.send_message(
+			MessageType::CameraImageRequest,
+			&camera::image_request(true),
+		)?;
+		Ok(CameraStream {
+			connection: &mut self.device.connection,
+			image: ImageAssembler::new(camera.key()),
+			requested: Instant::now(),
+			failed: false,
+		})
+	}
+
+	fn set_switch(&mut self, entity: &Entity, state: bool) -> Result<(), EspHomeError> {
+		let command = command::switch_command(&self.entities, entity, state)?;
+		self.device
+			.connection
+			.send_message(MessageType::SwitchCommandRequest, &command)
+	}
+}
+
+/// Iterator over the images of a camera, returned by [`AuthenticatedDevice::stream`].
+pub struct CameraStream<'a> {
+	connection: &'a mut Connection,
+	image: ImageAssembler,
+	requested: Instant,
+	failed: bool,
+}
+
+impl CameraStream<'_> {
+	fn next_image(&mut self) -> Result<Vec<u8>, EspHomeError> {
+		loop {
+			let Some(wait) = camera::STREAM_REQUEST_INTERVAL
+				.checked_sub(self.requested.elapsed())
+				.filter(|wait| !wait.is_zero())
+			else {
+				self.connection.send_message(
+					MessageType::CameraImageRequest,
+					&camera::image_request(true),
+				)?;
+				self.requested = Instant::now();
+				continue;
+			};
+			let image = &mut self.image;
+			let chunk = self.connection.next_event_matching_timeout(wait, |event| {
+				image.accepts(event).then(|| image.push(event))
+			})?;
+			if let Some(Some(jpeg)) = chunk {
+				return Ok(jpeg);
+			}
+		}
+	}
+}
+
+impl Iterator for CameraStream<'_> {
+	type Item = Result<Vec<u8>, EspHomeError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.failed {
+			return None;
+		}
+		let image = self.next_image();
+		self.failed = image.is_err();
+		Some(image)
 	}
 }
@@ -0,0 +1,4 @@
+use crate::{api, frame::Frame, EspHomeError, MessageType};
+use num_traits::FromPrimitive;
+
+include!(concat!(env!("OUT_DIR"), "/events.rs"));
@@ -0,0 +1,490 @@
+use crate::EspHomeError;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use snow::{HandshakeState, TransportState};
+
+const NOISE_PATTERN: &str = "Noise_NNpsk0_25519_ChaChaPoly_SHA256";
+const NOISE_PROLOGUE: &[u8] = b"NoiseAPIInit\0\0";
+const NOISE_PROTOCOL_CHOICE: u8 = 0x01;
+const NOISE_MAX_FRAME_LENGTH: usize = 65535;
+const NOISE_TAG_LENGTH: usize = 16;
+
+/// A single message received from the device: its type and the (decrypted) protobuf payload.
+#[derive(Debug)]
+pub(crate) struct Frame {
+	message_type: u32,
+	payload: Vec<u8>,
+}
+
+impl Frame {
+	pub(crate) fn new(message_type: u32, payload: Vec<u8>) -> Frame {
+		Frame {
+			message_type,
+			payload,
+		}
+	}
+
+	pub(crate) fn message_type(&self) -> u32 {
+		self.message_type
+	}
+
+	pub(crate) fn payload(&self) -> &[u8] {
+		&self.payload
+	}
+
+	pub(crate) fn parse<M>(&self) -> Result<M, EspHomeError>
+	where
+		M: protobuf::Message,
+	{
+		Ok(M::parse_from_bytes(&self.payload)?)
+	}
+}
+
+/// Framing used for messages on the wire.
+pub(crate) enum Framing {
+	/// Zero byte followed by varint length and varint type.
+	Plaintext,
+
+	/// Noise frames (indicator byte 0x01, 16-bit big endian length). The transport is set once the handshake has
+	/// completed.
+	Noise {
+		psk: [u8; 32],
+		transport: Option<Box<TransportState>>,
+	},
+}
+
+impl Framing {
+	/// Noise framing using the base64-encoded pre-shared key configured as `api: encryption: key:` on the device.
+	pub(crate) fn noise(key: &str) -> Result<Framing, EspHomeError> {
+		let psk = BASE64
+			.decode(key.trim())
+			.ok()
+			.and_then(|k| <[u8; 32]>::try_from(k).ok())
+			.ok_or(EspHomeError::MalformedEncryptionKey)?;
+
+		Ok(Framing::Noise {
+			psk,
+			transport: None,
+		})
+	}
+
+	/// Returns the pre-shared key when a Noise handshake still needs to be performed.
+	pub(crate) fn pending_handshake(&self) -> Option<[u8; 32]> {
+		match self {
+			Framing::Noise {
+				psk,
+				transport: None,
+			} => Some(*psk),
+			_ => None,
+		}
+	}
+
+	pub(crate) fn complete_handshake(&mut self, transport: TransportState) {
+		if let Framing::Noise { transport: t, .. } = self {
+			*t = Some(Box::new(transport));
+		}
+	}
+
+	/// Encode a message into the bytes that are to be written to the wire.
+	pub(crate) fn encode(
+		&mut self,
+		message_type: u32,
+		body: &[u8],
+	) -> Result<Vec<u8>, EspHomeError> {
+		match self {
+			Framing::Plaintext => {
+				let mut bytes = Vec::with_capacity(body.len() + 11);
+				bytes.push(0);
+				write_varint(&mut bytes, u32::try_from(body.len())?);
+				write_varint(&mut bytes, message_type);
+				bytes.extend_from_slice(body);
+				Ok(bytes)
+			}
+			Framing::Noise {
+				transport: Some(transport),
+				..
+			} => {
+				let mut plain = Vec::with_capacity(body.len() + 4);
+				plain.extend_from_slice(&u16::try_from(message_type)?.to_be_bytes());
+				plain.extend_from_slice(&u16::try_from(body.len())?.to_be_bytes());
+				plain.extend_from_slice(body);
+
+				let mut frame = vec![0u8; plain.len() + NOISE_TAG_LENGTH];
+				let frame_length = transport.write_message(&plain, &mut frame)?;
+				frame.truncate(frame_length);
+				noise_frame(&frame)
+			}
+			Framing::Noise {
+				transport: None, ..
+			} => Err(EspHomeError::HandshakeRequired),
+		}
+	}
+
+	/// Decrypt the payload of a Noise data frame: the message type and payload length (both 16-bit big endian),
+	/// followed by the payload.
+	pub(crate) fn decrypt(&mut self, frame: &[u8]) -> Result<Frame, EspHomeError> {
+		let Framing::Noise {
+			transport: Some(transport),
+			..
+		} = self
+		else {
+			return Err(EspHomeError::HandshakeRequired);
+		};
+
+		let mut plain = vec![0u8; frame.len()];
+		let plain_length = transport.read_message(frame, &mut plain)?;
+		if plain_length < 4 {
+			return Err(EspHomeError::MalformedFrame);
+		}
+
+		plain.truncate(plain_length);
+		let message_type = u32::from(u16::from_be_bytes([plain[0], plain[1]]));
+		let length = usize::from(u16::from_be_bytes([plain[2], plain[3]]));
+		if length != plain_length - 4 {
+			return Err(EspHomeError::MalformedFrame);
+		}
+		Ok(Frame::new(message_type, plain.split_off(4)))
+	}
+
+	/// Split a complete frame off the front of `buffer`, if it contains one. Frames with a payload larger than
+	/// `max_length` bytes are rejected as soon as their header has been received.
+	pub(crate) fn take_frame(
+		&mut self,
+		buffer: &mut Vec<u8>,
+		max_length: usize,
+	) -> Result<Option<Frame>, EspHomeError> {
+		match self {
+			Framing::Plaintext => take_plaintext_frame(buffer, max_length),
+			Framing::Noise { .. } => match take_noise_frame(buffer, max_length)? {
+				Some(frame) => Ok(Some(self.decrypt(&frame)?)),
+				None => Ok(None),
+			},
+		}
+	}
+}
+
+/// Check the indicator byte of a plaintext frame.
+pub(crate) fn check_plaintext_indicator(indicator: u8) -> Result<(), EspHomeError> {
+	match indicator {
+		0x00 => Ok(()),
+		0x01 => Err(EspHomeError::EncryptionRequired),
+		other => Err(EspHomeError::InvalidIndicator(other)),
+	}
+}
+
+/// Check the indicator byte of a Noise frame.
+pub(crate) fn check_noise_indicator(indicator: u8) -> Result<(), EspHomeError> {
+	match indicator {
+		0x01 => Ok(()),
+		0x00 => Err(EspHomeError::EncryptionNotSupported),
+		other => Err(EspHomeError::InvalidIndicator(other)),
+	}
+}
+
+/// Prepend the Noise frame header to `payload`.
+pub(crate) fn noise_frame(payload: &[u8]) -> Result<Vec<u8>, EspHomeError> {
+	check_length(payload.len(), NOISE_MAX_FRAME_LENGTH)?;
+	let mut bytes = Vec::with_capacity(payload.len() + 3);
+	bytes.push(0x01);
+	bytes.extend_from_slice(&u16::try_from(payload.len())?.to_be_bytes());
+	bytes.extend_from_slice(payload);
+	Ok(bytes)
+}
+
+/// Split a complete (still encrypted) Noise frame off the front of `buffer`, if it contains one.
+pub(crate) fn take_noise_frame(
+	buffer: &mut Vec<u8>,
+	max_length: usize,
+) -> Result<Option<Vec<u8>>, EspHomeError> {
+	let Some(&indicator) = buffer.first() else {
+		return Ok(None);
+	};
+	check_noise_indicator(indicator)?;
+
+	if buffer.len() < 3 {
+		return Ok(None);
+	}
+	let length = usize::from(u16::from_be_bytes([buffer[1], buffer[2]]));
+	check_length(length, max_length)?;
+	if buffer.len() < 3 + length {
+		return Ok(None);
+	}
+
+	let frame = buffer[3..3 + length].to_vec();
+	buffer.drain(0..3 + length);
+	Ok(Some(frame))
+}
+
+fn take_plaintext_frame(
+	buffer: &mut Vec<u8>,
+	max_length: usize,
+) -> Result<Option<Frame>, EspHomeError> {
+	let Some(&indicator) = buffer.first() else {
+		return Ok(None);
+	};
+	check_plaintext_indicator(indicator)?;
+
+	let Some((length, length_size)) = read_varint(&buffer[1..])? else {
+		return Ok(None);
+	};
+	let Some((message_type, type_size)) = read_varint(&buffer[1 + length_size..])? else {
+		return Ok(None);
+	};
+
+	let header_length = 1 + length_size + type_size;
+	let length = usize::try_from(length)?;
+	check_length(length, max_length)?;
+	if buffer.len() < header_length + length {
+		return Ok(None);
+	}
+
+	let payload = buffer[header_length..header_length + length].to_vec();
+	buffer.drain(0..header_length + length);
+	Ok(Some(Frame::new(message_type, payload)))
+}
+
+fn check_length(length: usize, maximum: usize) -> Result<(), EspHomeError> {
+	if length > maximum {
+		return Err(EspHomeError::FrameTooLarge { length, maximum });
+	}
+	Ok(())
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn write_varint(bytes: &mut Vec<u8>, mut value: u32) {
+	while value >= 0x80 {
+		bytes.push((value & 0x7f) as u8 | 0x80);
+		value >>= 7;
+	}
+	bytes.push(value as u8);
+}
+
+/// Read a varint from the start of `bytes`. Returns the value and the number of bytes it occupied, or `None` when
+/// more bytes are needed.
+fn read_varint(bytes: &[u8]) -> Result<Option<(u32, usize)>, EspHomeError> {
+	let mut value: u32 = 0;
+	for (index, byte) in bytes.iter().enumerate() {
+		if index >= 5 {
+			return Err(EspHomeError::MalformedFrame);
+		}
+		value |= u32::from(byte & 0x7f) << (7 * index);
+		if byte & 0x80 == 0 {
+			return Ok(Some((value, index + 1)));
+		}
+	}
+	Ok(None)
+}
+
+/// Client side of the Noise handshake.
+pub(crate) struct NoiseHandshake {
+	state: HandshakeState,
+}
+
+impl NoiseHandshake {
+	/// Start the handshake. Returns the bytes (client hello and first handshake message) to send to the device.
+	pub(crate) fn start(psk: &[u8; 32]) -> Result<(NoiseHandshake, Vec<u8>), EspHomeError> {
+		let mut state = snow::Builder::new(NOISE_PATTERN.parse()?)
+			.psk(0, psk)
+			.prologue(NOISE_PROLOGUE)
+			.build_initiator()?;
+
+		let mut message = vec![0u8; NOISE_MAX_FRAME_LENGTH];
+		let message_length = state.write_message(&[], &mut message)?;
+		let mut handshake_frame = vec![0u8];
+		handshake_frame.extend_from_slice(&message[0..message_length]);
+
+		let mut bytes = noise_frame(&[])?;
+		bytes.extend(noise_frame(&handshake_frame)?);
+		Ok((NoiseHandshake { state }, bytes))
+	}
+
+	/// Check the server hello: the chosen protocol followed by the (zero-terminated) server name.
+	pub(crate) fn server_hello(server_hello: &[u8]) -> Result<(), EspHomeError> {
+		match server_hello.first() {
+			Some(&NOISE_PROTOCOL_CHOICE) => Ok(()),
+			Some(other) => Err(EspHomeError::UnsupportedNoiseProtocol(*other)),
+			None => Err(EspHomeError::HandshakeFailed(
+				"server hello is empty".to_string(),
+			)),
+		}
+	}
+
+	/// Process the handshake response: a zero byte followed by the handshake message, or an error explanation.
+	pub(crate) fn finish(mut self, response: &[u8]) -> Result<TransportState, EspHomeError> {
+		match response.split_first() {
+			Some((0, message)) => {
+				let mut payload = vec![0u8; NOISE_MAX_FRAME_LENGTH];
+				self.state.read_message(message, &mut payload)?;
+				Ok(self.state.into_transport_mode()?)
+			}
+			Some((_, explanation)) => {
+				let explanation = String::from_utf8_lossy(explanation).to_string();
+				if explanation == "Handshake MAC failure" {
+					return Err(EspHomeError::InvalidEncryptionKey);
+				}
+				Err(EspHomeError::HandshakeFailed(explanation))
+			}
+			None => Err(EspHomeError::HandshakeFailed(
+				"handshake response is empty".to_string(),
+			)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn varint_round_trip() {
+		for value in [0, 1, 0x7f, 0x80, 300, 0x3fff, 0x4000, u32::MAX] {
+			let mut bytes = Vec::new();
+			write_varint(&mut bytes, value);
+			assert_eq!(read_varint(&bytes).unwrap(), Some((value, bytes.len())));
+		}
+	}
+
+	#[test]
+	fn varint_needs_more_bytes() {
+		assert_eq!(read_varint(&[]).unwrap(), None);
+		assert_eq!(read_varint(&[0x80]).unwrap(), None);
+		assert_eq!(read_varint(&[0xac, 0x02, 0xff]).unwrap(), Some((300, 2)));
+	}
+
+	#[test]
+	fn varint_too_long() {
+		assert!(matches!(
+			read_varint(&[0xff; 6]),
+			Err(EspHomeError::MalformedFrame)
+		));
+	}
+
+	#[test]
+	fn plaintext_frame_in_parts() {
+		let payload = vec![0x42; 300];
+		let bytes = Framing::Plaintext.encode(25, &payload).unwrap();
+
+		let mut buffer = Vec::new();
+		for byte in &bytes[..bytes.len() - 1] {
+			buffer.push(*byte);
+			assert!(take_plaintext_frame(&mut buffer, 1024).unwrap().is_none());
+		}
+		buffer.push(bytes[bytes.len() - 1]);
+		buffer.extend_from_slice(&bytes[..2]);
+
+		let frame = take_plaintext_frame(&mut buffer, 1024).unwrap().unwrap();
+		assert_eq!(frame.message_type(), 25);
+		assert_eq!(frame.payload(), &payload[..]);
+
+		// The start of the next frame stays in the buffer
+		assert_eq!(buffer, &bytes[..2]);
+	}
+
+	#[test]
+	fn plaintext_frame_too_large() {
+		let mut buffer = Framing::Plaintext.encode(25, &[0; 100]).unwrap();
+		buffer.truncate(3);
+		assert!(matches!(
+			take_plaintext_frame(&mut buffer, 99),
+			Err(EspHomeError::FrameTooLarge {
+				length: 100,
+				maximum: 99
+			})
+		));
+	}
+
+	#[test]
+	fn plaintext_frame_indicator() {
+		assert!(matches!(
+			take_plaintext_frame(&mut vec![0x01, 0, 0], 1024),
+			Err(EspHomeError::EncryptionRequired)
+		));
+		assert!(matches!(
+			take_plaintext_frame(&mut vec![0x02], 1024),
+			Err(EspHomeError::InvalidIndicator(2))
+		));
+	}
+
+	#[test]
+	fn noise_frame_in_parts() {
+		let bytes = noise_frame(&[1, 2, 3, 4]).unwrap();
+		assert_eq!(bytes, [0x01, 0x00, 0x04, 1, 2, 3, 4]);
+
+		let mut buffer = Vec::new();
+		for byte in &bytes[..bytes.len() - 1] {
+			buffer.push(*byte);
+			assert!(take_noise_frame(&mut buffer, 1024).unwrap().is_none());
+		}
+		buffer.push(bytes[bytes.len() - 1]);
+		buffer.push(0x01);
+
+		assert_eq!(
+			take_noise_frame(&mut buffer, 1024).unwrap(),
+			Some(vec![1, 2, 3, 4])
+		);
+		assert_eq!(buffer, [0x01]);
+	}
+
+	#[test]
+	fn noise_frame_too_large() {
+		let mut buffer = vec![0x01, 0x01, 0x00];
+		assert!(matches!(
+			take_noise_frame(&mut buffer, 255),
+			Err(EspHomeError::FrameTooLarge {
+				length: 256,
+				maximum: 255
+			})
+		));
+		assert!(matches!(
+			take_noise_frame(&mut vec![0x00], 1024),
+			Err(EspHomeError::EncryptionNotSupported)
+		));
+	}
+
+	/// Noise framing with a completed handshake, and the transport of the device it is connected to.
+	fn noise_pair() -> (Framing, TransportState) {
+		let psk = [7u8; 32];
+		let (handshake, bytes) = NoiseHandshake::start(&psk).unwrap();
+		let mut device = snow::Builder::new(NOISE_PATTERN.parse().unwrap())
+			.psk(0, &psk)
+			.prologue(NOISE_PROLOGUE)
+			.build_responder()
+			.unwrap();
+
+		// Skip the empty client hello frame, the header of the handshake frame and its leading zero byte
+		let mut payload = vec![0u8; NOISE_MAX_FRAME_LENGTH];
+		device.read_message(&bytes[7..], &mut payload).unwrap();
+		let mut response = vec![0u8; NOISE_MAX_FRAME_LENGTH];
+		let length = device.write_message(&[], &mut response[1..]).unwrap();
+		response.truncate(length + 1);
+
+		let mut framing = Framing::noise(&BASE64.encode(psk)).unwrap();
+		framing.complete_handshake(handshake.finish(&response).unwrap());
+		(framing, device.into_transport_mode().unwrap())
+	}
+
+	fn encrypt(device: &mut TransportState, plain: &[u8]) -> Vec<u8> {
+		let mut frame = vec![0u8; plain.len() + NOISE_TAG_LENGTH];
+		let length = device.write_message(plain, &mut frame).unwrap();
+		frame.truncate(length);
+		frame
+	}
+
+	#[test]
+	fn noise_decrypt() {
+		let (mut framing, mut device) = noise_pair();
+		let frame = framing
+			.decrypt(&encrypt(&mut device, &[0, 25, 0, 2, 0xaa, 0xbb]))
+			.unwrap();
+		assert_eq!(frame.message_type(), 25);
+		assert_eq!(frame.payload(), [0xaa, 0xbb]);
+	}
+
+	#[test]
+	fn noise_decrypt_checks_length() {
+		let (mut framing, mut device) = noise_pair();
+		assert!(matches!(
+			framing.decrypt(&encrypt(&mut device, &[0, 25, 0, 3, 0xaa, 0xbb])),
+			Err(EspHomeError::MalformedFrame)
+		));
+	}
+}
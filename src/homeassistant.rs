@@ -0,0 +1,222 @@
+use crate::api;
+use std::{
+	collections::HashMap,
+	sync::mpsc::{self, Receiver, Sender},
+};
+
+/// A Home Assistant service call (or event) requested by the device, for instance by a `homeassistant.service` action.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceCall {
+	service: String,
+	data: HashMap<String, String>,
+	data_template: HashMap<String, String>,
+	variables: HashMap<String, String>,
+	is_event: bool,
+}
+
+fn to_map(entries: &[api::HomeassistantServiceMap]) -> HashMap<String, String> {
+	entries
+		.iter()
+		.map(|e| (e.key.clone(), e.value.clone()))
+		.collect()
+}
+
+impl From<&api::HomeassistantServiceResponse> for ServiceCall {
+	fn from(response: &api::HomeassistantServiceResponse) -> Self {
+		ServiceCall {
+			service: response.service.clone(),
+			data: to_map(&response.data),
+			data_template: to_map(&response.data_template),
+			variables: to_map(&response.variables),
+			is_event: response.is_event,
+		}
+	}
+}
+
+impl ServiceCall {
+	/// Name of the service (such as `light.turn_on`), or of the event when [`ServiceCall::is_event`] is set.
+	#[must_use]
+	pub fn service(&self) -> &str {
+		&self.service
+	}
+
+	#[must_use]
+	pub fn data(&self) -> &HashMap<String, String> {
+		&self.data
+	}
+
+	/// Data values that are templates, to be rendered with [`ServiceCall::variables`].
+	#[must_use]
+	pub fn data_template(&self) -> &HashMap<String, String> {
+		&self.data_template
+	}
+
+	#[must_use]
+	pub fn variables(&self) -> &HashMap<String, String> {
+		&self.variables
+	}
+
+	#[must_use]
+	pub fn is_event(&self) -> bool {
+		self.is_event
+	}
+}
+
+type ServiceCallHandler = Box<dyn FnMut(&ServiceCall) + Send>;
+
+/// Handlers for service calls, by service name.
+#[derive(Default)]
+pub(crate) struct ServiceCallRouter {
+	handlers: HashMap<String, Vec<ServiceCallHandler>>,
+}
+
+impl ServiceCallRouter {
+	pub(crate) fn add<F>(&mut self, service: &str, handler: F)
+	where
+		F: FnMut(&ServiceCall) + Send + 'static,
+	{
+		self.handlers
+			.entry(service.to_string())
+			.or_default()
+			.push(Box::new(handler));
+	}
+
+	pub(crate) fn dispatch(&mut self, response: &api::HomeassistantServiceResponse) {
+		if let Some(handlers) = self.handlers.get_mut(&response.service) {
+			let call = ServiceCall::from(response);
+			for handler in handlers {
+				handler(&call);
+			}
+		}
+	}
+}
+
+/// Source of Home Assistant entity states that the device imports (`homeassistant` sensors), for use without Home
+/// Assistant.
+pub trait HomeAssistantStateProvider: Send {
+	/// Current state of `entity_id`, or of one of its attributes. `None` when unknown, in which case nothing is sent.
+	fn state(&mut self, entity_id: &str, attribute: Option<&str>) -> Option<String>;
+}
+
+impl<F> HomeAssistantStateProvider for F
+where
+	F: FnMut(&str, Option<&str>) -> Option<String> + Send,
+{
+	fn state(&mut self, entity_id: &str, attribute: Option<&str>) -> Option<String> {
+		self(entity_id, attribute)
+	}
+}
+
+/// States the device asked to import, answered from the provider.
+#[derive(Default)]
+pub(crate) struct StateImports {
+	provider: Option<Box<dyn HomeAssistantStateProvider>>,
+	requested: Vec<(String, Option<String>)>,
+	changes: Option<Receiver<String>>,
+}
+
+impl StateImports {
+	/// Use `provider` from now on. Returns the sender on which changes of its states are reported.
+	pub(crate) fn set_provider(
+		&mut self,
+		provider: Box<dyn HomeAssistantStateProvider>,
+	) -> Sender<String> {
+		let (sender, receiver) = mpsc::channel();
+		self.provider = Some(provider);
+		self.changes = Some(receiver);
+		sender
+	}
+
+	/// Record a request from the device and return the current value, if known.
+	pub(crate) fn request(
+		&mut self,
+		request: &api::SubscribeHomeAssistantStateResponse,
+	) -> Option<api::HomeAssistantStateResponse> {
+		let import = (
+			request.entity_id.clone(),
+			Some(request.attribute.clone()).filter(|a| !a.is_empty()),
+		);
+		if !self.requested.contains(&import) {
+			self.requested.push(import.clone());
+		}
+		self.response(&import.0, import.1.as_deref())
+	}
+
+	/// Current values of all requested states of `entity_id`.
+	pub(crate) fn changed(&mut self, entity_id: &str) -> Vec<api::HomeAssistantStateResponse> {
+		let attributes: Vec<Option<String>> = self
+			.requested
+			.iter()
+			.filter(|(id, _)| id == entity_id)
+			.map(|(_, attribute)| attribute.clone())
+			.collect();
+		attributes
+			.iter()
+			.filter_map(|attribute| self.response(entity_id, attribute.as_deref()))
+			.collect()
+	}
+
+	/// Current values of all requested states of the entities reported as changed since the last call.
+	pub(crate) fn take_changes(&mut self) -> Vec<api::HomeAssistantStateResponse> {
+		let Some(changes) = &self.changes else {
+			return Vec::new();
+		};
+		let entity_ids: Vec<String> = changes.try_iter().collect();
+		entity_ids
+			.iter()
+			.flat_map(|entity_id| self.changed(entity_id))
+			.collect()
+	}
+
+	fn response(
+		&mut self,
+		entity_id: &str,
+		attribute: Option<&str>,
+	) -> Option<api::HomeAssistantStateResponse> {
+		let state = self.provider.as_mut()?.state(entity_id, attribute)?;
+		let mut response = api::HomeAssistantStateResponse::new();
+		response.entity_id = entity_id.to_string();
+		response.attribute = attribute.unwrap_or_default().to_string();
+		response.state = state;
+		Some(response)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn subscribe(imports: &mut StateImports, entity_id: &str, attribute: &str) -> Option<String> {
+		let mut request = api::SubscribeHomeAssistantStateResponse::new();
+		request.entity_id = entity_id.to_string();
+		request.attribute = attribute.to_string();
+		imports.request(&request).map(|response| response.state)
+	}
+
+	#[test]
+	fn reports_changes() {
+		let mut imports = StateImports::default();
+		assert!(imports.take_changes().is_empty());
+
+		let changes = imports.set_provider(Box::new(|entity_id: &str, attribute: Option<&str>| {
+			(entity_id == "sun.sun").then(|| attribute.unwrap_or("above_horizon").to_string())
+		}));
+		assert_eq!(
+			subscribe(&mut imports, "sun.sun", ""),
+			Some("above_horizon".to_string())
+		);
+		assert_eq!(
+			subscribe(&mut imports, "sun.sun", "elevation"),
+			Some("elevation".to_string())
+		);
+		assert_eq!(subscribe(&mut imports, "light.kitchen", ""), None);
+
+		changes.send("sun.sun".to_string()).unwrap();
+		changes.send("person.guest".to_string()).unwrap();
+		let responses = imports.take_changes();
+		let attributes: Vec<&str> = responses.iter().map(|r| r.attribute.as_str()).collect();
+		assert_eq!(attributes, ["", "elevation"]);
+		assert!(responses.iter().all(|r| r.entity_id == "sun.sun"));
+		assert!(imports.take_changes().is_empty());
+	}
+}
@@ -7,12 +7,38 @@
 //#![allow(clippy::cast_possible_truncation)]
 #![allow(clippy::struct_excessive_bools)]
 
-
-mod api;
-mod api_options;
+/// Protobuf types generated from `api.proto` by build.rs.
+#[allow(clippy::pedantic, renamed_and_removed_lints)]
+mod generated {
+	include!(concat!(env!("OUT_DIR"), "/mod.rs"));
+}
+pub use generated::api;
+#[cfg(feature = "tokio")]
+pub mod async_connection;
+#[cfg(feature = "tokio")]
+pub mod async_device;
+pub mod bluetooth;
+pub mod camera;
+pub mod command;
 pub mod connection;
 pub mod device;
+pub mod event;
+mod frame;
+pub mod homeassistant;
+pub mod log;
 pub mod model;
+pub mod subscription;
+#[cfg(feature = "tokio")]
+pub use async_connection::*;
+#[cfg(feature = "tokio")]
+pub use async_device::*;
+pub use bluetooth::*;
+pub use camera::*;
+pub use command::*;
 pub use connection::*;
 pub use device::*;
+pub use event::*;
+pub use homeassistant::*;
+pub use log::*;
 pub use model::*;
+pub use subscription::*;
@@ -0,0 +1,179 @@
+use crate::api;
+
+/// A log message received from the device after subscribing to its logs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogRecord {
+	level: api::LogLevel,
+	tag: Option<String>,
+	line: Option<u32>,
+	message: String,
+	send_failed: bool,
+}
+
+impl LogRecord {
+	/// Parse a log line as formatted by `ESPHome` (`[D][sensor:123]: message`), stripping color codes. Lines that are
+	/// not in this format are kept as the message.
+	#[must_use]
+	pub fn parse(level: api::LogLevel, line: &str) -> LogRecord {
+		let text = strip_ansi(line);
+		let mut record = LogRecord {
+			level,
+			tag: None,
+			line: None,
+			message: text.clone(),
+			send_failed: false,
+		};
+
+		// Skip the level letter, which is also sent as a separate field
+		let Some(rest) = text
+			.strip_prefix('[')
+			.and_then(|t| t.split_once(']'))
+			.map(|(_, rest)| rest)
+		else {
+			return record;
+		};
+		let Some((source, message)) = rest.strip_prefix('[').and_then(|t| t.split_once(']')) else {
+			return record;
+		};
+
+		let (tag, line) = match source.rsplit_once(':') {
+			Some((tag, line)) if line.parse::<u32>().is_ok() => (tag, line.parse().ok()),
+			_ => (source, None),
+		};
+		record.tag = Some(tag.to_string());
+		record.line = line;
+		record.message = message
+			.strip_prefix(':')
+			.unwrap_or(message)
+			.trim_start_matches(' ')
+			.to_string();
+		record
+	}
+
+	#[must_use]
+	pub fn level(&self) -> api::LogLevel {
+		self.level
+	}
+
+	/// The component that logged the message (such as `sensor` or `wifi`).
+	#[must_use]
+	pub fn tag(&self) -> Option<&str> {
+		self.tag.as_deref()
+	}
+
+	/// The line in the component's source file that logged the message.
+	#[must_use]
+	pub fn line(&self) -> Option<u32> {
+		self.line
+	}
+
+	#[must_use]
+	pub fn message(&self) -> &str {
+		&self.message
+	}
+
+	/// Whether the device failed to send earlier log messages (which are then missing).
+	#[must_use]
+	pub fn send_failed(&self) -> bool {
+		self.send_failed
+	}
+}
+
+impl From<&api::SubscribeLogsResponse> for LogRecord {
+	fn from(response: &api::SubscribeLogsResponse) -> Self {
+		let mut record =
+			LogRecord::parse(response.level.enum_value_or_default(), &response.message);
+		record.send_failed = response.send_failed;
+		record
+	}
+}
+
+/// Remove ANSI escape sequences (used by `ESPHome` to color log lines).
+fn strip_ansi(text: &str) -> String {
+	let mut stripped = String::with_capacity(text.len());
+	let mut chars = text.chars();
+	while let Some(c) = chars.next() {
+		if c != '\u{1b}' {
+			stripped.push(c);
+			continue;
+		}
+
+		// Control sequences end with a byte in the range @ to ~
+		if chars.next() == Some('[') {
+			for c in chars.by_ref() {
+				if ('@'..='~').contains(&c) {
+					break;
+				}
+			}
+		}
+	}
+	stripped
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use api::LogLevel;
+
+	#[test]
+	fn parse_colored_line() {
+		let record = LogRecord::parse(
+			LogLevel::LOG_LEVEL_DEBUG,
+			"\u{1b}[0;36m[D][sensor:123]: 'Temperature': Sending state 21.50 °C\u{1b}[0m",
+		);
+		assert_eq!(record.level(), LogLevel::LOG_LEVEL_DEBUG);
+		assert_eq!(record.tag(), Some("sensor"));
+		assert_eq!(record.line(), Some(123));
+		assert_eq!(record.message(), "'Temperature': Sending state 21.50 °C");
+		assert!(!record.send_failed());
+	}
+
+	#[test]
+	fn parse_without_line() {
+		let record = LogRecord::parse(LogLevel::LOG_LEVEL_INFO, "[I][app]:Running");
+		assert_eq!(record.tag(), Some("app"));
+		assert_eq!(record.line(), None);
+		assert_eq!(record.message(), "Running");
+
+		// Only a number after the last colon is a line
+		let record = LogRecord::parse(LogLevel::LOG_LEVEL_INFO, "[I][esp32.preferences]: Saving");
+		assert_eq!(record.tag(), Some("esp32.preferences"));
+		let record = LogRecord::parse(LogLevel::LOG_LEVEL_INFO, "[I][a:b]: x");
+		assert_eq!(record.tag(), Some("a:b"));
+		assert_eq!(record.line(), None);
+	}
+
+	#[test]
+	fn parse_other_formats() {
+		for line in ["Plain message", "[D] no tag", "[D][unterminated", ""] {
+			let record = LogRecord::parse(LogLevel::LOG_LEVEL_CONFIG, line);
+			assert_eq!(record.tag(), None, "{line}");
+			assert_eq!(record.line(), None);
+			assert_eq!(record.message(), line);
+		}
+
+		let record = LogRecord::parse(LogLevel::LOG_LEVEL_CONFIG, "[C][]");
+		assert_eq!(record.tag(), Some(""));
+		assert_eq!(record.message(), "");
+	}
+
+	#[test]
+	fn strip_escape_sequences() {
+		assert_eq!(strip_ansi("\u{1b}[1;31mred\u{1b}[0m text"), "red text");
+		assert_eq!(strip_ansi("no color"), "no color");
+		assert_eq!(strip_ansi("cut off\u{1b}[12"), "cut off");
+	}
+
+	#[test]
+	fn from_response() {
+		let mut response = api::SubscribeLogsResponse::new();
+		response.level = LogLevel::LOG_LEVEL_WARN.into();
+		response.message = "[W][wifi:42]: Weak signal".to_string();
+		response.send_failed = true;
+		let record = LogRecord::from(&response);
+		assert_eq!(record.level(), LogLevel::LOG_LEVEL_WARN);
+		assert_eq!(record.tag(), Some("wifi"));
+		assert_eq!(record.line(), Some(42));
+		assert!(record.send_failed());
+	}
+}
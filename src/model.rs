@@ -1,4 +1,6 @@
+use crate::api;
 use num_derive::FromPrimitive;
+use protobuf::EnumOrUnknown;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -12,6 +14,64 @@ pub enum EspHomeError {
 		received: u32,
 	},
 
+	#[error("The encryption key is not a base64-encoded 32-byte key")]
+	MalformedEncryptionKey,
+
+	#[error("The device rejected the encryption key")]
+	InvalidEncryptionKey,
+
+	#[error("The device requires an encrypted connection")]
+	EncryptionRequired,
+
+	#[error("The device expects a plaintext connection")]
+	EncryptionNotSupported,
+
+	#[error("The device chose an unsupported encryption protocol ({0})")]
+	UnsupportedNoiseProtocol(u8),
+
+	#[error("The encryption handshake failed: {0}")]
+	HandshakeFailed(String),
+
+	#[error("The encryption handshake has not been performed")]
+	HandshakeRequired,
+
+	#[error("Received a frame with an invalid indicator byte ({0})")]
+	InvalidIndicator(u8),
+
+	#[error("Received a malformed frame")]
+	MalformedFrame,
+
+	#[error("Frame of {length} bytes exceeds the maximum frame length of {maximum} bytes")]
+	FrameTooLarge { length: usize, maximum: usize },
+
+	#[error("No entity with key {0} was listed by the device")]
+	UnknownEntity(u32),
+
+	#[error("Entity {key} is of type {actual:?}, expected {expected:?}")]
+	WrongEntityType {
+		key: u32,
+		expected: EntityType,
+		actual: EntityType,
+	},
+
+	#[error("The state of entity {0} is not known")]
+	UnknownState(u32),
+
+	#[error("Timed out waiting for the device")]
+	Timeout,
+
+	#[error("Read timeouts cannot be set on the stream of the connection")]
+	TimeoutUnsupported,
+
+	#[error("Invalid command for entity {key}: {reason}")]
+	InvalidCommand { key: u32, reason: String },
+
+	#[error("The device is not a Bluetooth proxy")]
+	NoBluetoothProxy,
+
+	#[error("Noise error: {0}")]
+	Noise(#[from] snow::Error),
+
 	#[error("IO error: {0}")]
 	Io(#[from] std::io::Error),
 
@@ -25,18 +85,235 @@ pub enum EspHomeError {
 	TryFromIntError(#[from] std::num::TryFromIntError),
 }
 
-#[derive(Debug, Clone)]
+/// Last known state of an entity. Switches are reported as [`State::Binary`], numbers as [`State::Measurement`] and
+/// selects as [`State::Text`].
+#[derive(Debug, Clone, PartialEq)]
 pub enum State {
+	/// The device does not have a valid state for the entity (for instance because a sensor has not been read yet).
+	Unknown,
 	Binary(bool),
 	Measurement(f32),
 	Text(String),
+	Climate(ClimateState),
+	Cover(CoverState),
+	Fan(FanState),
+	Light(LightState),
+	Lock(api::LockState),
+	MediaPlayer(MediaPlayerState),
 }
 
-#[derive(Debug)]
-#[allow(dead_code)]
-pub struct ExtendedInfo {
-	pub(crate) object_id: String,
-	pub(crate) unique_id: String,
+/// Declares a struct wrapping the state message of an entity.
+macro_rules! entity_state {
+	($(#[$meta: meta])* $name: ident, $message_type: ty) => {
+		$(#[$meta])*
+		#[derive(Debug, Clone, PartialEq)]
+		pub struct $name {
+			state: $message_type,
+		}
+
+		impl From<$message_type> for $name {
+			fn from(state: $message_type) -> Self {
+				$name { state }
+			}
+		}
+	};
+}
+
+entity_state!(ClimateState, api::ClimateStateResponse);
+
+impl ClimateState {
+	#[must_use]
+	pub fn mode(&self) -> api::ClimateMode {
+		self.state.mode.enum_value_or_default()
+	}
+
+	#[must_use]
+	pub fn current_temperature(&self) -> f32 {
+		self.state.current_temperature
+	}
+
+	#[must_use]
+	pub fn target_temperature(&self) -> f32 {
+		self.state.target_temperature
+	}
+
+	#[must_use]
+	pub fn target_temperature_low(&self) -> f32 {
+		self.state.target_temperature_low
+	}
+
+	#[must_use]
+	pub fn target_temperature_high(&self) -> f32 {
+		self.state.target_temperature_high
+	}
+
+	/// Whether away mode is on (sent by older firmware instead of the away preset).
+	#[must_use]
+	pub fn legacy_away(&self) -> bool {
+		self.state.legacy_away
+	}
+
+	#[must_use]
+	pub fn action(&self) -> api::ClimateAction {
+		self.state.action.enum_value_or_default()
+	}
+
+	#[must_use]
+	pub fn fan_mode(&self) -> api::ClimateFanMode {
+		self.state.fan_mode.enum_value_or_default()
+	}
+
+	#[must_use]
+	pub fn swing_mode(&self) -> api::ClimateSwingMode {
+		self.state.swing_mode.enum_value_or_default()
+	}
+
+	#[must_use]
+	pub fn custom_fan_mode(&self) -> &str {
+		&self.state.custom_fan_mode
+	}
+
+	#[must_use]
+	pub fn preset(&self) -> api::ClimatePreset {
+		self.state.preset.enum_value_or_default()
+	}
+
+	#[must_use]
+	pub fn custom_preset(&self) -> &str {
+		&self.state.custom_preset
+	}
+}
+
+entity_state!(CoverState, api::CoverStateResponse);
+
+impl CoverState {
+	/// Position between 0.0 (closed) and 1.0 (open).
+	#[must_use]
+	pub fn position(&self) -> f32 {
+		self.state.position
+	}
+
+	#[must_use]
+	pub fn tilt(&self) -> f32 {
+		self.state.tilt
+	}
+
+	#[must_use]
+	pub fn current_operation(&self) -> api::CoverOperation {
+		self.state.current_operation.enum_value_or_default()
+	}
+
+	/// Open or closed, as reported by firmware older than API 1.1 (which does not report the position).
+	#[must_use]
+	pub fn legacy_state(&self) -> api::LegacyCoverState {
+		self.state.legacy_state.enum_value_or_default()
+	}
+}
+
+entity_state!(FanState, api::FanStateResponse);
+
+impl FanState {
+	#[must_use]
+	pub fn is_on(&self) -> bool {
+		self.state.state
+	}
+
+	#[must_use]
+	pub fn oscillating(&self) -> bool {
+		self.state.oscillating
+	}
+
+	#[must_use]
+	pub fn direction(&self) -> api::FanDirection {
+		self.state.direction.enum_value_or_default()
+	}
+
+	/// Speed between 1 and the supported speed count (see [`FanInfo::supported_speed_count`]).
+	#[must_use]
+	pub fn speed_level(&self) -> i32 {
+		self.state.speed_level
+	}
+
+	/// Speed as reported by older firmware, which does not report the speed level.
+	#[must_use]
+	pub fn legacy_speed(&self) -> api::FanSpeed {
+		self.state.speed.enum_value_or_default()
+	}
+}
+
+entity_state!(LightState, api::LightStateResponse);
+
+impl LightState {
+	#[must_use]
+	pub fn is_on(&self) -> bool {
+		self.state.state
+	}
+
+	#[must_use]
+	pub fn brightness(&self) -> f32 {
+		self.state.brightness
+	}
+
+	#[must_use]
+	pub fn color_mode(&self) -> api::ColorMode {
+		self.state.color_mode.enum_value_or_default()
+	}
+
+	#[must_use]
+	pub fn color_brightness(&self) -> f32 {
+		self.state.color_brightness
+	}
+
+	/// Red, green and blue components, each between 0.0 and 1.0.
+	#[must_use]
+	pub fn rgb(&self) -> (f32, f32, f32) {
+		(self.state.red, self.state.green, self.state.blue)
+	}
+
+	#[must_use]
+	pub fn white(&self) -> f32 {
+		self.state.white
+	}
+
+	/// Color temperature in mireds.
+	#[must_use]
+	pub fn color_temperature(&self) -> f32 {
+		self.state.color_temperature
+	}
+
+	#[must_use]
+	pub fn cold_white(&self) -> f32 {
+		self.state.cold_white
+	}
+
+	#[must_use]
+	pub fn warm_white(&self) -> f32 {
+		self.state.warm_white
+	}
+
+	#[must_use]
+	pub fn effect(&self) -> &str {
+		&self.state.effect
+	}
+}
+
+entity_state!(MediaPlayerState, api::MediaPlayerStateResponse);
+
+impl MediaPlayerState {
+	#[must_use]
+	pub fn state(&self) -> api::MediaPlayerState {
+		self.state.state.enum_value_or_default()
+	}
+
+	#[must_use]
+	pub fn volume(&self) -> f32 {
+		self.state.volume
+	}
+
+	#[must_use]
+	pub fn muted(&self) -> bool {
+		self.state.muted
+	}
 }
 
 #[derive(Debug)]
@@ -62,65 +339,508 @@ impl Entity {
 	pub fn key(&self) -> u32 {
 		self.info.key
 	}
+
+	#[must_use]
+	pub fn name(&self) -> &str {
+		&self.info.name
+	}
+
+	#[must_use]
+	pub fn kind(&self) -> &EntityKind {
+		&self.kind
+	}
+
+	#[must_use]
+	pub fn entity_type(&self) -> EntityType {
+		self.kind.entity_type()
+	}
 }
 
 #[derive(Debug)]
 pub enum EntityKind {
-	BinarySensor(ExtendedInfo),
-	Camera(ExtendedInfo),
-	Climate(ExtendedInfo),
-	Cover(ExtendedInfo),
-	Fan(ExtendedInfo),
-	Light(ExtendedInfo),
-	Number(ExtendedInfo),
-	Select(ExtendedInfo),
-	Sensor(ExtendedInfo),
+	BinarySensor(BinarySensorInfo),
+	Button(ButtonInfo),
+	Camera(CameraInfo),
+	Climate(ClimateInfo),
+	Cover(CoverInfo),
+	Fan(FanInfo),
+	Light(LightInfo),
+	Lock(LockInfo),
+	MediaPlayer(MediaPlayerInfo),
+	Number(NumberInfo),
+	Select(SelectInfo),
+	Sensor(SensorInfo),
+	Services(ServiceInfo),
+	Switch(SwitchInfo),
+	TextSensor(TextSensorInfo),
+}
+
+impl EntityKind {
+	#[must_use]
+	pub fn entity_type(&self) -> EntityType {
+		match self {
+			EntityKind::BinarySensor(_) => EntityType::BinarySensor,
+			EntityKind::Button(_) => EntityType::Button,
+			EntityKind::Camera(_) => EntityType::Camera,
+			EntityKind::Climate(_) => EntityType::Climate,
+			EntityKind::Cover(_) => EntityType::Cover,
+			EntityKind::Fan(_) => EntityType::Fan,
+			EntityKind::Light(_) => EntityType::Light,
+			EntityKind::Lock(_) => EntityType::Lock,
+			EntityKind::MediaPlayer(_) => EntityType::MediaPlayer,
+			EntityKind::Number(_) => EntityType::Number,
+			EntityKind::Select(_) => EntityType::Select,
+			EntityKind::Sensor(_) => EntityType::Sensor,
+			EntityKind::Services(_) => EntityType::Services,
+			EntityKind::Switch(_) => EntityType::Switch,
+			EntityKind::TextSensor(_) => EntityType::TextSensor,
+		}
+	}
+}
+
+/// The kind of an entity, without its metadata.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EntityType {
+	BinarySensor,
+	Button,
+	Camera,
+	Climate,
+	Cover,
+	Fan,
+	Light,
+	Lock,
+	MediaPlayer,
+	Number,
+	Select,
+	Sensor,
 	Services,
-	Switch(ExtendedInfo),
-	TextSensor(ExtendedInfo),
-}
-
-#[derive(Debug, Copy, Clone, FromPrimitive)]
-pub enum MessageType {
-	HelloRequest = 1,
-	HelloResponse = 2,
-	ConnectRequest = 3,
-	ConnectResponse = 4,
-	DisconnectRequest = 5,
-	DisconnectResponse = 6,
-	PingRequest = 7,
-	PingResponse = 8,
-	DeviceInfoRequest = 9,
-	DeviceInfoResponse = 10,
-	ListEntitiesRequest = 11,
-	ListEntitiesBinarySensorResponse = 12,
-	ListEntitiesCoverResponse = 13,
-	ListEntitiesFanResponse = 14,
-	ListEntitiesLightResponse = 15,
-	ListEntitiesSensorResponse = 16,
-	ListEntitiesSwitchResponse = 17,
-	ListEntitiesTextSensorResponse = 18,
-	ListEntitiesDoneResponse = 19,
-	SubscribeStatesRequest = 20,
-
-	BinarySensorStateResponse = 21,
-	CoverStateResponse = 22,
-	FanStateResponse = 23,
-	LightStateResponse = 24,
-	SensorStateResponse = 25,
-	SwitchStateResponse = 26,
-	TextSensorStateResponse = 27,
-
-	ClimateStateResponse = 47,
-	NumberStateResponse = 50,
-	SelectStateResponse = 53,
-
-	GetTimeRequest = 36,
-	GetTimeResponse = 37,
-
-	ListEntitiesServicesResponse = 41,
-	ListEntitiesCameraResponse = 43,
-	ListEntitiesClimateResponse = 46,
-	ListEntitiesNumberResponse = 49,
-	ListEntitiesSelectResponse = 52,
+	Switch,
+	TextSensor,
+}
+
+/// Convert a list of protobuf enum values, skipping values unknown to this crate.
+fn known_values<E>(values: &[EnumOrUnknown<E>]) -> Vec<E>
+where
+	E: protobuf::Enum,
+{
+	values.iter().filter_map(|v| v.enum_value().ok()).collect()
+}
+
+/// Declares the information struct for a kind of entity, wrapping the message it was listed with, and implements the
+/// accessors for the metadata all kinds of entities share.
+macro_rules! entity_info {
+	($(#[$meta: meta])* $name: ident, $message_type: ty) => {
+		$(#[$meta])*
+		#[derive(Debug, Clone)]
+		pub struct $name {
+			info: $message_type,
+		}
+
+		impl From<$message_type> for $name {
+			fn from(info: $message_type) -> Self {
+				$name { info }
+			}
+		}
+
+		impl $name {
+			#[must_use]
+			pub fn object_id(&self) -> &str {
+				&self.info.object_id
+			}
+
+			#[must_use]
+			pub fn unique_id(&self) -> &str {
+				&self.info.unique_id
+			}
+
+			#[must_use]
+			pub fn icon(&self) -> &str {
+				&self.info.icon
+			}
+
+			#[must_use]
+			pub fn entity_category(&self) -> api::EntityCategory {
+				self.info.entity_category.enum_value_or_default()
+			}
+
+			#[must_use]
+			pub fn disabled_by_default(&self) -> bool {
+				self.info.disabled_by_default
+			}
+		}
+	};
+}
+
+entity_info!(BinarySensorInfo, api::ListEntitiesBinarySensorResponse);
+
+impl BinarySensorInfo {
+	#[must_use]
+	pub fn device_class(&self) -> &str {
+		&self.info.device_class
+	}
+
+	#[must_use]
+	pub fn is_status_binary_sensor(&self) -> bool {
+		self.info.is_status_binary_sensor
+	}
+}
+
+entity_info!(ButtonInfo, api::ListEntitiesButtonResponse);
+
+impl ButtonInfo {
+	#[must_use]
+	pub fn device_class(&self) -> &str {
+		&self.info.device_class
+	}
+}
+
+entity_info!(CameraInfo, api::ListEntitiesCameraResponse);
+
+entity_info!(ClimateInfo, api::ListEntitiesClimateResponse);
+
+impl ClimateInfo {
+	#[must_use]
+	pub fn supports_current_temperature(&self) -> bool {
+		self.info.supports_current_temperature
+	}
+
+	#[must_use]
+	pub fn supports_two_point_target_temperature(&self) -> bool {
+		self.info.supports_two_point_target_temperature
+	}
+
+	#[must_use]
+	pub fn supported_modes(&self) -> Vec<api::ClimateMode> {
+		known_values(&self.info.supported_modes)
+	}
+
+	#[must_use]
+	pub fn visual_min_temperature(&self) -> f32 {
+		self.info.visual_min_temperature
+	}
+
+	#[must_use]
+	pub fn visual_max_temperature(&self) -> f32 {
+		self.info.visual_max_temperature
+	}
+
+	#[must_use]
+	pub fn visual_temperature_step(&self) -> f32 {
+		self.info.visual_temperature_step
+	}
+
+	/// Whether the device supports the away flag (replaced by presets in newer firmware).
+	#[must_use]
+	pub fn legacy_supports_away(&self) -> bool {
+		self.info.legacy_supports_away
+	}
+
+	#[must_use]
+	pub fn supports_action(&self) -> bool {
+		self.info.supports_action
+	}
+
+	#[must_use]
+	pub fn supported_fan_modes(&self) -> Vec<api::ClimateFanMode> {
+		known_values(&self.info.supported_fan_modes)
+	}
+
+	#[must_use]
+	pub fn supported_swing_modes(&self) -> Vec<api::ClimateSwingMode> {
+		known_values(&self.info.supported_swing_modes)
+	}
+
+	#[must_use]
+	pub fn supported_custom_fan_modes(&self) -> &[String] {
+		&self.info.supported_custom_fan_modes
+	}
+
+	#[must_use]
+	pub fn supported_presets(&self) -> Vec<api::ClimatePreset> {
+		known_values(&self.info.supported_presets)
+	}
+
+	#[must_use]
+	pub fn supported_custom_presets(&self) -> &[String] {
+		&self.info.supported_custom_presets
+	}
+}
+
+entity_info!(CoverInfo, api::ListEntitiesCoverResponse);
+
+impl CoverInfo {
+	#[must_use]
+	pub fn assumed_state(&self) -> bool {
+		self.info.assumed_state
+	}
+
+	#[must_use]
+	pub fn supports_position(&self) -> bool {
+		self.info.supports_position
+	}
+
+	#[must_use]
+	pub fn supports_tilt(&self) -> bool {
+		self.info.supports_tilt
+	}
+
+	#[must_use]
+	pub fn device_class(&self) -> &str {
+		&self.info.device_class
+	}
+}
+
+entity_info!(FanInfo, api::ListEntitiesFanResponse);
+
+impl FanInfo {
+	#[must_use]
+	pub fn supports_oscillation(&self) -> bool {
+		self.info.supports_oscillation
+	}
+
+	#[must_use]
+	pub fn supports_speed(&self) -> bool {
+		self.info.supports_speed
+	}
+
+	#[must_use]
+	pub fn supports_direction(&self) -> bool {
+		self.info.supports_direction
+	}
+
+	#[must_use]
+	pub fn supported_speed_count(&self) -> i32 {
+		self.info.supported_speed_count
+	}
+}
+
+entity_info!(LightInfo, api::ListEntitiesLightResponse);
+
+impl LightInfo {
+	/// The color modes the light supports. For firmware that predates color modes, the mode is derived from the
+	/// legacy capability flags.
+	#[must_use]
+	pub fn supported_color_modes(&self) -> Vec<api::ColorMode> {
+		if !self.info.supported_color_modes.is_empty() {
+			return known_values(&self.info.supported_color_modes);
+		}
+
+		let info = &self.info;
+		let mode = if info.legacy_supports_rgb {
+			if info.legacy_supports_color_temperature {
+				api::ColorMode::COLOR_MODE_RGB_COLOR_TEMPERATURE
+			} else if info.legacy_supports_white_value {
+				api::ColorMode::COLOR_MODE_RGB_WHITE
+			} else {
+				api::ColorMode::COLOR_MODE_RGB
+			}
+		} else if info.legacy_supports_color_temperature {
+			api::ColorMode::COLOR_MODE_COLOR_TEMPERATURE
+		} else if info.legacy_supports_white_value {
+			api::ColorMode::COLOR_MODE_WHITE
+		} else if info.legacy_supports_brightness {
+			api::ColorMode::COLOR_MODE_BRIGHTNESS
+		} else {
+			api::ColorMode::COLOR_MODE_ON_OFF
+		};
+		vec![mode]
+	}
+
+	#[must_use]
+	pub fn min_mireds(&self) -> f32 {
+		self.info.min_mireds
+	}
+
+	#[must_use]
+	pub fn max_mireds(&self) -> f32 {
+		self.info.max_mireds
+	}
+
+	#[must_use]
+	pub fn effects(&self) -> &[String] {
+		&self.info.effects
+	}
+}
+
+entity_info!(LockInfo, api::ListEntitiesLockResponse);
+
+impl LockInfo {
+	#[must_use]
+	pub fn assumed_state(&self) -> bool {
+		self.info.assumed_state
+	}
+
+	#[must_use]
+	pub fn supports_open(&self) -> bool {
+		self.info.supports_open
+	}
+
+	#[must_use]
+	pub fn requires_code(&self) -> bool {
+		self.info.requires_code
+	}
+
+	/// Format of the code (such as a regular expression), if the device specifies one.
+	#[must_use]
+	pub fn code_format(&self) -> &str {
+		&self.info.code_format
+	}
+}
+
+entity_info!(MediaPlayerInfo, api::ListEntitiesMediaPlayerResponse);
+
+impl MediaPlayerInfo {
+	#[must_use]
+	pub fn supports_pause(&self) -> bool {
+		self.info.supports_pause
+	}
+}
+
+entity_info!(NumberInfo, api::ListEntitiesNumberResponse);
+
+impl NumberInfo {
+	#[must_use]
+	pub fn min_value(&self) -> f32 {
+		self.info.min_value
+	}
+
+	#[must_use]
+	pub fn max_value(&self) -> f32 {
+		self.info.max_value
+	}
+
+	#[must_use]
+	pub fn step(&self) -> f32 {
+		self.info.step
+	}
+
+	#[must_use]
+	pub fn unit_of_measurement(&self) -> &str {
+		&self.info.unit_of_measurement
+	}
+
+	#[must_use]
+	pub fn mode(&self) -> api::NumberMode {
+		self.info.mode.enum_value_or_default()
+	}
+}
+
+entity_info!(SelectInfo, api::ListEntitiesSelectResponse);
+
+impl SelectInfo {
+	#[must_use]
+	pub fn options(&self) -> &[String] {
+		&self.info.options
+	}
+}
+
+entity_info!(SensorInfo, api::ListEntitiesSensorResponse);
+
+impl SensorInfo {
+	#[must_use]
+	pub fn unit_of_measurement(&self) -> &str {
+		&self.info.unit_of_measurement
+	}
+
+	#[must_use]
+	pub fn accuracy_decimals(&self) -> i32 {
+		self.info.accuracy_decimals
+	}
+
+	#[must_use]
+	pub fn force_update(&self) -> bool {
+		self.info.force_update
+	}
+
+	#[must_use]
+	pub fn device_class(&self) -> &str {
+		&self.info.device_class
+	}
+
+	#[must_use]
+	pub fn state_class(&self) -> api::SensorStateClass {
+		self.info.state_class.enum_value_or_default()
+	}
+}
+
+/// A user-defined service, which can be called with [`AuthenticatedDevice::execute_service`](crate::AuthenticatedDevice::execute_service).
+#[derive(Debug, Clone)]
+pub struct ServiceInfo {
+	info: api::ListEntitiesServicesResponse,
+}
+
+impl From<api::ListEntitiesServicesResponse> for ServiceInfo {
+	fn from(info: api::ListEntitiesServicesResponse) -> Self {
+		ServiceInfo { info }
+	}
+}
+
+impl ServiceInfo {
+	/// Names and types of the arguments of the service, in the order they are to be passed.
+	#[must_use]
+	pub fn arguments(&self) -> Vec<(&str, api::ServiceArgType)> {
+		self.info
+			.args
+			.iter()
+			.map(|a| (a.name.as_str(), a.type_.enum_value_or_default()))
+			.collect()
+	}
+}
+
+entity_info!(SwitchInfo, api::ListEntitiesSwitchResponse);
+
+impl SwitchInfo {
+	#[must_use]
+	pub fn assumed_state(&self) -> bool {
+		self.info.assumed_state
+	}
+
+	#[must_use]
+	pub fn device_class(&self) -> &str {
+		&self.info.device_class
+	}
+}
+
+entity_info!(TextSensorInfo, api::ListEntitiesTextSensorResponse);
+
+/// Which side of the connection sends a message (the `source` option in `api.proto`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MessageSource {
+	Both,
+	Server,
+	Client,
+}
+
+include!(concat!(env!("OUT_DIR"), "/message_types.rs"));
+
+impl MessageType {
+	fn definition(self) -> &'static MessageDefinition {
+		let index = MESSAGE_DEFINITIONS
+			.binary_search_by_key(&(self as u32), |d| d.message_type as u32)
+			.expect("message type without definition");
+		&MESSAGE_DEFINITIONS[index]
+	}
+
+	/// Which side of the connection sends messages of this type.
+	#[must_use]
+	pub fn source(self) -> MessageSource {
+		self.definition().source
+	}
+
+	/// The define (such as `USE_LIGHT`) the firmware must be built with to support this message, if any.
+	#[must_use]
+	pub fn ifdef(self) -> Option<&'static str> {
+		self.definition().ifdef
+	}
+
+	/// Whether the message should be sent without delay.
+	#[must_use]
+	pub fn no_delay(self) -> bool {
+		self.definition().no_delay
+	}
+
+	/// Decode the payload of a message of this type.
+	pub fn decode(self, payload: &[u8]) -> Result<Box<dyn protobuf::MessageDyn>, EspHomeError> {
+		Ok((self.definition().decode)(payload)?)
+	}
 }
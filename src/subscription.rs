@@ -0,0 +1,168 @@
+use crate::{model::State, Entity, EntityType};
+use std::{collections::HashMap, sync::mpsc};
+
+/// A change of the state of an entity, passed to state subscribers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateChange {
+	pub key: u32,
+	pub entity_type: EntityType,
+
+	/// The state before the change, or `None` if this is the first state received for the entity.
+	pub previous: Option<State>,
+	pub state: State,
+}
+
+/// Selects the state changes a subscriber is notified of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateFilter {
+	All,
+
+	/// Changes of the entity with the given key.
+	Key(u32),
+
+	/// Changes of all entities of the given type.
+	Type(EntityType),
+}
+
+impl StateFilter {
+	#[must_use]
+	pub fn matches(&self, change: &StateChange) -> bool {
+		match self {
+			StateFilter::All => true,
+			StateFilter::Key(key) => change.key == *key,
+			StateFilter::Type(entity_type) => change.entity_type == *entity_type,
+		}
+	}
+}
+
+impl From<&Entity> for StateFilter {
+	fn from(entity: &Entity) -> Self {
+		StateFilter::Key(entity.key())
+	}
+}
+
+impl From<EntityType> for StateFilter {
+	fn from(entity_type: EntityType) -> Self {
+		StateFilter::Type(entity_type)
+	}
+}
+
+enum Subscriber {
+	Callback(Box<dyn FnMut(&StateChange) + Send>),
+	Channel(mpsc::Sender<StateChange>),
+}
+
+/// Last known states of entities, and the subscribers to notify when they change.
+#[derive(Default)]
+pub(crate) struct StateTracker {
+	states: HashMap<u32, State>,
+	subscribers: Vec<(StateFilter, Subscriber)>,
+}
+
+impl StateTracker {
+	pub(crate) fn get(&self, key: u32) -> Option<&State> {
+		self.states.get(&key)
+	}
+
+	pub(crate) fn subscribe(&mut self, filter: StateFilter) -> mpsc::Receiver<StateChange> {
+		let (sender, receiver) = mpsc::channel();
+		self.subscribers.push((filter, Subscriber::Channel(sender)));
+		receiver
+	}
+
+	pub(crate) fn on_change<F>(&mut self, filter: StateFilter, callback: F)
+	where
+		F: FnMut(&StateChange) + Send + 'static,
+	{
+		self.subscribers
+			.push((filter, Subscriber::Callback(Box::new(callback))));
+	}
+
+	/// Record the state of an entity. Subscribers are notified when it differs from the previous state. Channels whose
+	/// receiver has been dropped are removed.
+	pub(crate) fn update(&mut self, key: u32, entity_type: EntityType, state: State) {
+		let previous = self.states.insert(key, state.clone());
+		if previous.as_ref() == Some(&state) {
+			return;
+		}
+
+		let change = StateChange {
+			key,
+			entity_type,
+			previous,
+			state,
+		};
+		self.subscribers.retain_mut(|(filter, subscriber)| {
+			if !filter.matches(&change) {
+				return true;
+			}
+			match subscriber {
+				Subscriber::Callback(callback) => {
+					callback(&change);
+					true
+				}
+				Subscriber::Channel(sender) => sender.send(change.clone()).is_ok(),
+			}
+		});
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn notifies_on_change() {
+		let mut tracker = StateTracker::default();
+		let changes = tracker.subscribe(StateFilter::All);
+		tracker.update(1, EntityType::Sensor, State::Measurement(1.0));
+		tracker.update(1, EntityType::Sensor, State::Measurement(1.0));
+		tracker.update(1, EntityType::Sensor, State::Measurement(2.0));
+
+		let first = changes.try_recv().unwrap();
+		assert_eq!(first.previous, None);
+		assert_eq!(first.state, State::Measurement(1.0));
+		let second = changes.try_recv().unwrap();
+		assert_eq!(second.previous, Some(State::Measurement(1.0)));
+		assert_eq!(second.state, State::Measurement(2.0));
+		assert!(changes.try_recv().is_err());
+		assert_eq!(tracker.get(1), Some(&State::Measurement(2.0)));
+	}
+
+	#[test]
+	fn filters() {
+		let mut tracker = StateTracker::default();
+		let by_key = tracker.subscribe(StateFilter::Key(2));
+		let by_type = tracker.subscribe(StateFilter::Type(EntityType::Switch));
+		let (sender, called) = mpsc::channel();
+		tracker.on_change(StateFilter::Key(1), move |change| {
+			sender.send(change.key).unwrap();
+		});
+		tracker.update(1, EntityType::Sensor, State::Measurement(1.0));
+		tracker.update(2, EntityType::Switch, State::Binary(true));
+
+		assert_eq!(by_key.try_iter().map(|c| c.key).collect::<Vec<_>>(), [2]);
+		assert_eq!(
+			by_type
+				.try_iter()
+				.map(|c| c.entity_type)
+				.collect::<Vec<_>>(),
+			[EntityType::Switch]
+		);
+		assert_eq!(called.try_iter().collect::<Vec<_>>(), [1]);
+	}
+
+	#[test]
+	fn removes_dropped_receivers() {
+		let mut tracker = StateTracker::default();
+		drop(tracker.subscribe(StateFilter::Key(1)));
+		let kept = tracker.subscribe(StateFilter::All);
+
+		// Subscribers are only removed when a change for them fails to be sent
+		tracker.update(2, EntityType::Sensor, State::Unknown);
+		assert_eq!(tracker.subscribers.len(), 2);
+		tracker.update(1, EntityType::Sensor, State::Unknown);
+		assert_eq!(tracker.subscribers.len(), 1);
+		assert_eq!(kept.try_iter().count(), 2);
+	}
+}